@@ -0,0 +1,313 @@
+//! Transitions and easing curves used to animate between expressions.
+
+use core::{array, f32::consts::TAU, time::Duration};
+
+use crate::{
+    KerfurElements,
+    element::PARTS,
+    math::{ceilf, exp2f, sinf, sqrtf},
+};
+
+/// How the face animates toward a new expression.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transition {
+    /// Move all elements toward their targets at a constant speed.
+    ///
+    /// The speed is in pixels per second, and applies to the element that
+    /// moves the furthest. Other elements move slower so that every element
+    /// arrives at the same time.
+    Speed(f32),
+    /// Move all elements toward their targets over a fixed duration.
+    Timed(Duration, Easing),
+    /// Pull each element toward its target with a damped spring.
+    ///
+    /// The first value is the stiffness of the springs and the second is
+    /// their damping. Springs with less damping than
+    /// [`Transition::critical_spring`] overshoot their targets and bounce
+    /// before settling, while springs with more damping settle slowly
+    /// without overshooting. Springs without any damping never settle.
+    ///
+    /// Each element has its own spring, so an element that was already
+    /// moving keeps its speed when the target changes, and overshoots
+    /// further than the elements that start at rest.
+    Spring(f32, f32),
+}
+
+impl Transition {
+    /// A [`Transition::Spring`] that overshoots and bounces before settling.
+    pub const BOUNCY: Self = Self::Spring(400., 16.);
+    /// The default [`Transition`].
+    pub const DEFAULT: Self = Self::Timed(Duration::from_millis(200), Easing::EaseInOut);
+    /// A [`Transition`] that immediately shows the new expression.
+    pub const IMMEDIATE: Self = Self::Timed(Duration::ZERO, Easing::Linear);
+
+    /// Create a [`Transition::Timed`] with the given duration and easing.
+    #[inline]
+    #[must_use]
+    pub const fn timed(duration: Duration, easing: Easing) -> Self { Self::Timed(duration, easing) }
+
+    /// Create a [`Transition::Speed`] with the given speed.
+    #[inline]
+    #[must_use]
+    pub const fn speed(pixels_per_second: f32) -> Self { Self::Speed(pixels_per_second) }
+
+    /// Create a [`Transition::Spring`] with the given stiffness and damping.
+    #[inline]
+    #[must_use]
+    pub const fn spring(stiffness: f32, damping: f32) -> Self { Self::Spring(stiffness, damping) }
+
+    /// Create a critically damped [`Transition::Spring`] with the given
+    /// stiffness.
+    ///
+    /// This is the least damping a spring can have without overshooting,
+    /// so it settles as quickly as possible.
+    #[inline]
+    #[must_use]
+    pub fn critical_spring(stiffness: f32) -> Self {
+        Self::Spring(stiffness, 2. * sqrtf(stiffness.max(0.)))
+    }
+}
+
+impl Default for Transition {
+    fn default() -> Self { Self::DEFAULT }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The state of the springs pulling each part of the face toward its
+/// target, as used by [`Transition::Spring`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct SpringState {
+    parts: [Spring; PARTS],
+}
+
+/// A spring pulling the progress of one part of the face toward `1.0`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Spring {
+    position: f32,
+    velocity: f32,
+    distance: f32,
+}
+
+impl SpringState {
+    /// The longest time the springs are moved by at once, in seconds.
+    ///
+    /// Longer frames only move the springs this far, so a stalled frame
+    /// can't stall the next one while the springs catch up.
+    const MAX_ELAPSED: f32 = 0.1;
+    /// The longest time step used to move the springs, in seconds.
+    ///
+    /// Longer steps are split up so stiff springs don't fly apart.
+    const MAX_STEP: f32 = 0.001;
+    /// Springs at rest, with nowhere to go.
+    pub(crate) const REST: Self = Self { parts: [Spring::REST; PARTS] };
+    /// How close a part must be to its target to settle, in pixels.
+    const REST_DISTANCE: f32 = 0.5;
+    /// How slowly a part must move to settle, in pixels per second.
+    const REST_SPEED: f32 = 4.;
+    /// The time used to measure how fast each part is moving, in seconds.
+    const SPEED_STEP: f32 = 0.05;
+
+    /// Start the springs from `current` toward a new `target`.
+    ///
+    /// Each part keeps the speed it was closing in on the new target at,
+    /// while moving along the previous transition from `start` to `previous`.
+    #[must_use]
+    pub(crate) fn retarget(
+        self,
+        (start, previous): (&KerfurElements, &KerfurElements),
+        current: &KerfurElements,
+        target: &KerfurElements,
+    ) -> Self {
+        let ahead = self.parts.map(|part| part.position + part.velocity * Self::SPEED_STEP);
+        let now = KerfurElements::interpolate_parts(start, previous, self.positions());
+        let now = KerfurElements::distances(&now, target);
+        let ahead = KerfurElements::interpolate_parts(start, previous, ahead);
+        let ahead = KerfurElements::distances(&ahead, target);
+        let distances = KerfurElements::distances(current, target);
+
+        Self {
+            parts: array::from_fn(|part| {
+                // Convert the speed in pixels to a speed in progress
+                let (speed, distance) =
+                    ((now[part] - ahead[part]) / Self::SPEED_STEP, distances[part]);
+                let velocity = if distance > 0. { speed / distance } else { 0. };
+                Spring { position: 0., velocity, distance }
+            }),
+        }
+    }
+
+    /// Get how far along the transition each part is.
+    ///
+    /// This is `0.0` at the start and `1.0` at the target,
+    /// but goes past `1.0` when a spring overshoots.
+    #[must_use]
+    pub(crate) fn positions(&self) -> [f32; PARTS] { self.parts.map(|part| part.position) }
+
+    /// Get how far along the transition the furthest behind part is.
+    ///
+    /// This stays below `1.0` until every part has settled.
+    #[must_use]
+    pub(crate) fn progress(&self) -> f32 {
+        let position = self.parts.iter().map(|part| part.position).fold(f32::INFINITY, f32::min);
+        position.clamp(0., 1. - f32::EPSILON)
+    }
+
+    /// Move the springs forward by the given number of seconds,
+    /// snapping each part onto its target once it has settled.
+    ///
+    /// Returns the number of seconds it took for every part to settle,
+    /// or `None` if some parts are still moving.
+    #[expect(clippy::cast_possible_truncation, reason = "Steps are limited by MAX_ELAPSED")]
+    #[expect(clippy::cast_sign_loss, reason = "Steps will never be negative")]
+    pub(crate) fn step(&mut self, stiffness: f32, damping: f32, elapsed: f32) -> Option<f32> {
+        let elapsed = elapsed.clamp(0., Self::MAX_ELAPSED);
+        let steps = ceilf(elapsed / Self::MAX_STEP).max(1.);
+        let step = elapsed / steps;
+        let mut used = 0.;
+        for _ in 0..steps as u32 {
+            for part in &mut self.parts {
+                let acceleration = stiffness * (1. - part.position) - damping * part.velocity;
+                part.velocity += acceleration * step;
+                part.position += part.velocity * step;
+            }
+            used += step;
+            if self.settle() {
+                return Some(used);
+            }
+        }
+        None
+    }
+
+    /// Snap every part that has settled onto its target.
+    ///
+    /// Returns `true` once every part has settled.
+    fn settle(&mut self) -> bool {
+        let mut settled = true;
+        for part in &mut self.parts {
+            if (1. - part.position).abs() * part.distance < Self::REST_DISTANCE
+                && part.velocity.abs() * part.distance < Self::REST_SPEED
+            {
+                *part = Spring { position: 1., velocity: 0., distance: part.distance };
+            } else {
+                settled = false;
+            }
+        }
+        settled
+    }
+}
+
+impl Spring {
+    /// A spring at rest at the start of a transition.
+    const REST: Self = Self { position: 0., velocity: 0., distance: 0. };
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// An easing curve, used to change the rate of an animation over time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Easing {
+    /// Constant speed
+    #[default]
+    Linear,
+
+    /// Start slowly, then speed up
+    EaseIn,
+    /// Start quickly, then slow down
+    EaseOut,
+    /// Start and end slowly
+    EaseInOut,
+
+    /// Like [`Easing::EaseIn`], but more pronounced
+    CubicIn,
+    /// Like [`Easing::EaseOut`], but more pronounced
+    CubicOut,
+    /// Like [`Easing::EaseInOut`], but more pronounced
+    CubicInOut,
+
+    /// Pull back slightly before starting
+    BackIn,
+    /// Overshoot slightly before ending
+    BackOut,
+    /// Pull back slightly before starting and overshoot before ending
+    BackInOut,
+
+    /// Wind up with a growing oscillation before starting
+    ElasticIn,
+    /// Settle with a shrinking oscillation after ending
+    ElasticOut,
+    /// Both [`Easing::ElasticIn`] and [`Easing::ElasticOut`]
+    ElasticInOut,
+}
+
+impl Easing {
+    /// Apply the easing curve to a linear progress value.
+    ///
+    /// The input is clamped between `0.0` and `1.0`, and the output is always
+    /// `0.0` at the start and `1.0` at the end.
+    ///
+    /// Some curves, like [`Easing::BackOut`],
+    /// may return values outside of that range.
+    #[must_use]
+    pub fn apply(self, t: f32) -> f32 {
+        const BACK: f32 = 1.701_58;
+        const BACK_IN_OUT: f32 = BACK * 1.525;
+        const ELASTIC: f32 = TAU / 3.;
+        const ELASTIC_IN_OUT: f32 = TAU / 4.5;
+
+        let t = t.clamp(0., 1.);
+        if t <= 0. || t >= 1. {
+            return t;
+        }
+
+        match self {
+            Easing::Linear => t,
+
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => 1. - (1. - t) * (1. - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    2. * t * t
+                } else {
+                    1. - 2. * (1. - t) * (1. - t)
+                }
+            }
+
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => 1. - (1. - t) * (1. - t) * (1. - t),
+            Easing::CubicInOut => {
+                if t < 0.5 {
+                    4. * t * t * t
+                } else {
+                    1. - 4. * (1. - t) * (1. - t) * (1. - t)
+                }
+            }
+
+            Easing::BackIn => t * t * ((BACK + 1.) * t - BACK),
+            Easing::BackOut => {
+                let t = t - 1.;
+                1. + t * t * ((BACK + 1.) * t + BACK)
+            }
+            Easing::BackInOut => {
+                if t < 0.5 {
+                    let t = 2. * t;
+                    t * t * ((BACK_IN_OUT + 1.) * t - BACK_IN_OUT) / 2.
+                } else {
+                    let t = 2. * t - 2.;
+                    t * t * ((BACK_IN_OUT + 1.) * t + BACK_IN_OUT) / 2. + 1.
+                }
+            }
+
+            Easing::ElasticIn => -exp2f(10. * t - 10.) * sinf((t * 10. - 10.75) * ELASTIC),
+            Easing::ElasticOut => exp2f(-10. * t) * sinf((t * 10. - 0.75) * ELASTIC) + 1.,
+            Easing::ElasticInOut => {
+                let wave = sinf((20. * t - 11.125) * ELASTIC_IN_OUT);
+                if t < 0.5 {
+                    -exp2f(20. * t - 10.) * wave / 2.
+                } else {
+                    exp2f(-20. * t + 10.) * wave / 2. + 1.
+                }
+            }
+        }
+    }
+}
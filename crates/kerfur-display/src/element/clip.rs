@@ -0,0 +1,33 @@
+use embedded_graphics::{Pixel, prelude::*, primitives::Rectangle};
+
+/// A [`DrawTarget`] that only draws the pixels a mask allows.
+///
+/// Used to clip elements to shapes that aren't rectangles,
+/// such as the area between a pair of eyelids.
+pub(super) struct Masked<'a, D, F> {
+    target: &'a mut D,
+    mask: F,
+}
+
+impl<'a, D: DrawTarget, F: Fn(Point) -> bool> Masked<'a, D, F> {
+    /// Create a new [`Masked`] target, drawing only where `mask` returns
+    /// `true`.
+    pub(super) const fn new(target: &'a mut D, mask: F) -> Self { Self { target, mask } }
+}
+
+impl<D: DrawTarget, F: Fn(Point) -> bool> Dimensions for Masked<'_, D, F> {
+    fn bounding_box(&self) -> Rectangle { self.target.bounding_box() }
+}
+
+impl<D: DrawTarget, F: Fn(Point) -> bool> DrawTarget for Masked<'_, D, F> {
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let mask = &self.mask;
+        self.target.draw_iter(pixels.into_iter().filter(|Pixel(point, _)| mask(*point)))
+    }
+}
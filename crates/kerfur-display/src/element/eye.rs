@@ -1,18 +1,40 @@
+use core::f32::consts::TAU;
+
 use embedded_graphics::{
     prelude::*,
-    primitives::{Ellipse, Line, PrimitiveStyle, StyledDrawable},
+    primitives::{Circle, ContainsPoint, Ellipse, Line, PrimitiveStyle, Rectangle, StyledDrawable},
 };
 
 use crate::{
-    KerfurStyle,
-    element::{interp_angle, interp_line, interp_point, interp_size},
-    primitive::{ConstSector, Swirl},
+    KerfurLayout, KerfurStyle,
+    element::{
+        KerfurEyelids, KerfurHighlights, clip::Masked, distance_angle, distance_ellipse,
+        distance_line, distance_point, distance_rectangle, distance_sector, distance_u32,
+        layout_circle, layout_line, layout_sector, lerp_angle, lerp_ellipse, lerp_line, lerp_point,
+        lerp_rectangle, lerp_sector, lerp_u32, saturating_add, saturating_sub,
+    },
+    math::{roundf, sqrtf, wrap_angle},
+    primitive::{Caret, ConstSector, Cross, Heart, Star, Swirl, mirror_ellipse, mirror_line},
 };
 
 #[derive(Clone, Copy, PartialEq)]
 pub(super) struct EyeState {
     pub(super) left: KerfurEyeType,
     pub(super) right: KerfurEyeType,
+    pub(super) left_lids: KerfurEyelids,
+    pub(super) right_lids: KerfurEyelids,
+    pub(super) left_highlights: KerfurHighlights,
+    pub(super) right_highlights: KerfurHighlights,
+    pub(super) spin: f32,
+    pub(super) clip_pupils: bool,
+}
+
+/// The styles used to draw a single eye.
+struct EyeStyle<'a, C: PixelColor> {
+    inner: &'a PrimitiveStyle<C>,
+    outer: &'a PrimitiveStyle<C>,
+    line: &'a PrimitiveStyle<C>,
+    highlight: &'a PrimitiveStyle<C>,
 }
 
 /// The type of eye to display
@@ -26,6 +48,14 @@ pub enum KerfurEyeType {
     Line(Line),
     /// A swirl
     Swirl(Swirl),
+    /// A heart
+    Heart(Heart),
+    /// A star
+    Star(Star),
+    /// A cross, drawn with the eye line style
+    Cross(Cross),
+    /// A caret, drawn with the eye line style
+    Caret(Caret),
 }
 
 impl KerfurEyeType {
@@ -45,164 +75,672 @@ impl KerfurEyeType {
         Ellipse::with_center(Point::new(480 * 24 / 100, 240), Size::new_equal(480 * 22 / 100)),
     );
     /// The default, neutral right eye.
-    pub const NEUTRAL_RIGHT: KerfurEyeType = KerfurEyeType::Ellipse(
-        Ellipse::with_center(Point::new(480 * 76 / 100, 240), Size::new_equal(480 * 32 / 100)),
-        Ellipse::with_center(Point::new(480 * 76 / 100, 240), Size::new_equal(480 * 22 / 100)),
-    );
+    pub const NEUTRAL_RIGHT: KerfurEyeType = Self::NEUTRAL_LEFT.mirrored();
 
     /// Returns the eye with the pupil translated by the given amount.
     ///
-    /// # Panics
-    ///
-    /// Panics if the eye is not of type [`KerfurEyeType::Ellipse`].
+    /// Only [`KerfurEyeType::Ellipse`] and [`KerfurEyeType::Arrow`] eyes have
+    /// a pupil, other eyes are returned unchanged.
     #[must_use]
     pub const fn with_pupil_translated(mut self, by: Point) -> Self {
-        let KerfurEyeType::Ellipse(_, inner) = &mut self else {
-            panic!("Eye is not of type `KerfurEyeType::Ellipse`!")
-        };
-
-        inner.top_left.x += by.x;
-        inner.top_left.y += by.y;
-
+        match &mut self {
+            KerfurEyeType::Ellipse(_, inner) => {
+                inner.top_left = saturating_add(inner.top_left, by);
+            }
+            KerfurEyeType::Arrow(_, inner) => {
+                inner.top_left = saturating_add(inner.top_left, by);
+            }
+            KerfurEyeType::Line(_)
+            | KerfurEyeType::Swirl(_)
+            | KerfurEyeType::Heart(_)
+            | KerfurEyeType::Star(_)
+            | KerfurEyeType::Cross(_)
+            | KerfurEyeType::Caret(_) => {}
+        }
         self
     }
 
     /// Returns the eye with the pupil resized by the given amount.
     ///
-    /// # Panics
+    /// The pupil keeps the same center, and is never resized below zero.
+    /// [`KerfurEyeType::Arrow`] pupils are circular, so only `by.x` is used.
     ///
-    /// Panics if the eye is not of type [`KerfurEyeType::Ellipse`].
+    /// Only [`KerfurEyeType::Ellipse`] and [`KerfurEyeType::Arrow`] eyes have
+    /// a pupil, other eyes are returned unchanged.
     #[must_use]
     pub const fn with_pupil_resized(mut self, by: Point) -> Self {
-        let KerfurEyeType::Ellipse(_, inner) = &mut self else {
-            panic!("Eye is not of type `KerfurEyeType::Ellipse`!")
+        match &mut self {
+            KerfurEyeType::Ellipse(_, inner) => {
+                let center = saturating_add(inner.top_left, center_offset(inner.size));
+                inner.size.width = inner.size.width.saturating_add_signed(by.x);
+                inner.size.height = inner.size.height.saturating_add_signed(by.y);
+                inner.top_left = saturating_sub(center, center_offset(inner.size));
+            }
+            KerfurEyeType::Arrow(_, inner) => {
+                let size = Size::new_equal(inner.diameter);
+                let center = saturating_add(inner.top_left, center_offset(size));
+                inner.diameter = inner.diameter.saturating_add_signed(by.x);
+                let size = Size::new_equal(inner.diameter);
+                inner.top_left = saturating_sub(center, center_offset(size));
+            }
+            KerfurEyeType::Line(_)
+            | KerfurEyeType::Swirl(_)
+            | KerfurEyeType::Heart(_)
+            | KerfurEyeType::Star(_)
+            | KerfurEyeType::Cross(_)
+            | KerfurEyeType::Caret(_) => {}
+        }
+        self
+    }
+
+    /// Returns the eye with the pupil scaled around its center.
+    ///
+    /// Only [`KerfurEyeType::Ellipse`] and [`KerfurEyeType::Arrow`] eyes have
+    /// a pupil, other eyes are returned unchanged.
+    #[must_use]
+    pub fn with_pupil_scaled(mut self, scale: f32) -> Self {
+        match &mut self {
+            KerfurEyeType::Ellipse(_, inner) => {
+                *inner = Ellipse::with_center(inner.center(), scale_size(inner.size, scale));
+            }
+            KerfurEyeType::Arrow(_, inner) => {
+                let center = inner.center();
+                inner.diameter = scale_u32(inner.diameter, scale);
+                inner.top_left = Circle::with_center(center, inner.diameter).top_left;
+            }
+            KerfurEyeType::Line(_)
+            | KerfurEyeType::Swirl(_)
+            | KerfurEyeType::Heart(_)
+            | KerfurEyeType::Star(_)
+            | KerfurEyeType::Cross(_)
+            | KerfurEyeType::Caret(_) => {}
+        }
+        self
+    }
+
+    /// Returns the eye scaled around the center of its bounding box.
+    ///
+    /// Negative scales are treated as zero.
+    #[must_use]
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        let scale = scale.max(0.);
+        let origin = self.bounding_box().center();
+        let scale_center = |center: Point| origin + scale_point(center - origin, scale);
+        let scale_rectangle = |rectangle: &mut Rectangle| {
+            *rectangle = Rectangle::with_center(
+                scale_center(rectangle.center()),
+                scale_size(rectangle.size, scale),
+            );
         };
 
-        inner.top_left.x -= by.x / 2;
-        inner.top_left.y -= by.y / 2;
+        match &mut self {
+            KerfurEyeType::Ellipse(outer, inner) => {
+                *outer = Ellipse::with_center(
+                    scale_center(outer.center()),
+                    scale_size(outer.size, scale),
+                );
+                *inner = Ellipse::with_center(
+                    scale_center(inner.center()),
+                    scale_size(inner.size, scale),
+                );
+            }
+            KerfurEyeType::Arrow(outer, inner) => {
+                for sector in [outer, inner] {
+                    let circle = Circle::with_center(
+                        scale_center(sector.center()),
+                        scale_u32(sector.diameter, scale),
+                    );
+                    sector.top_left = circle.top_left;
+                    sector.diameter = circle.diameter;
+                }
+            }
+            KerfurEyeType::Line(line) => {
+                *line = Line::new(scale_center(line.start), scale_center(line.end));
+            }
+            KerfurEyeType::Swirl(swirl) => {
+                swirl.circle = Circle::with_center(
+                    scale_center(swirl.circle.center()),
+                    scale_u32(swirl.circle.diameter, scale),
+                );
+            }
+            KerfurEyeType::Heart(Heart { rectangle })
+            | KerfurEyeType::Star(Star { rectangle })
+            | KerfurEyeType::Cross(Cross { rectangle })
+            | KerfurEyeType::Caret(Caret { rectangle }) => scale_rectangle(rectangle),
+        }
+        self
+    }
 
-        if let Some(result) = inner.size.width.checked_add_signed(by.x) {
-            inner.size.width = result;
-        } else {
-            inner.size.width = 0;
+    /// Returns the eye mirrored across the face's vertical center line.
+    ///
+    /// This turns a left eye into a right eye, and a right eye into a left eye.
+    ///
+    /// See [`primitive`](crate::primitive) for how each shape is mirrored.
+    #[must_use]
+    pub const fn mirrored(self) -> Self {
+        match self {
+            KerfurEyeType::Ellipse(outer, inner) => {
+                KerfurEyeType::Ellipse(mirror_ellipse(outer), mirror_ellipse(inner))
+            }
+            KerfurEyeType::Arrow(outer, inner) => {
+                KerfurEyeType::Arrow(outer.mirrored(), inner.mirrored())
+            }
+            KerfurEyeType::Line(line) => KerfurEyeType::Line(mirror_line(line)),
+            KerfurEyeType::Swirl(swirl) => KerfurEyeType::Swirl(swirl.mirrored()),
+            KerfurEyeType::Heart(heart) => KerfurEyeType::Heart(heart.mirrored()),
+            KerfurEyeType::Star(star) => KerfurEyeType::Star(star.mirrored()),
+            KerfurEyeType::Cross(cross) => KerfurEyeType::Cross(cross.mirrored()),
+            KerfurEyeType::Caret(caret) => KerfurEyeType::Caret(caret.mirrored()),
         }
-        if let Some(result) = inner.size.height.checked_add_signed(by.y) {
-            inner.size.height = result;
-        } else {
-            inner.size.height = 0;
+    }
+}
+
+impl Dimensions for KerfurEyeType {
+    fn bounding_box(&self) -> Rectangle {
+        match self {
+            KerfurEyeType::Ellipse(outer, _) => outer.bounding_box(),
+            KerfurEyeType::Arrow(outer, _) => outer.into_sector().bounding_box(),
+            KerfurEyeType::Line(line) => line.bounding_box(),
+            KerfurEyeType::Swirl(swirl) => swirl.bounding_box(),
+            KerfurEyeType::Heart(heart) => heart.bounding_box(),
+            KerfurEyeType::Star(star) => star.bounding_box(),
+            KerfurEyeType::Cross(cross) => cross.bounding_box(),
+            KerfurEyeType::Caret(caret) => caret.bounding_box(),
         }
+    }
+}
+
+impl Transform for KerfurEyeType {
+    fn translate(&self, by: Point) -> Self {
+        let mut eye = *self;
+        eye.translate_mut(by);
+        eye
+    }
 
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        match self {
+            KerfurEyeType::Ellipse(outer, inner) => {
+                outer.top_left = saturating_add(outer.top_left, by);
+                inner.top_left = saturating_add(inner.top_left, by);
+            }
+            KerfurEyeType::Arrow(outer, inner) => {
+                outer.top_left = saturating_add(outer.top_left, by);
+                inner.top_left = saturating_add(inner.top_left, by);
+            }
+            KerfurEyeType::Line(line) => {
+                line.start = saturating_add(line.start, by);
+                line.end = saturating_add(line.end, by);
+            }
+            KerfurEyeType::Swirl(swirl) => {
+                swirl.circle.top_left = saturating_add(swirl.circle.top_left, by);
+            }
+            KerfurEyeType::Heart(Heart { rectangle })
+            | KerfurEyeType::Star(Star { rectangle })
+            | KerfurEyeType::Cross(Cross { rectangle })
+            | KerfurEyeType::Caret(Caret { rectangle }) => {
+                rectangle.top_left = saturating_add(rectangle.top_left, by);
+            }
+        }
         self
     }
 }
 
 impl EyeState {
+    /// The number of parts of the eyes that move separately.
+    pub(super) const PARTS: usize = 6;
+
     pub(super) fn draw<D: DrawTargetExt>(
         &mut self,
         display: &mut D,
         style: &KerfurStyle<D::Color>,
     ) -> Result<(), D::Error> {
+        let left = EyeStyle {
+            inner: &style.left_eye_inner,
+            outer: &style.left_eye_outer,
+            line: &style.left_eye_line,
+            highlight: &style.left_eye_highlight,
+        };
+        let right = EyeStyle {
+            inner: &style.right_eye_inner,
+            outer: &style.right_eye_outer,
+            line: &style.right_eye_line,
+            highlight: &style.right_eye_highlight,
+        };
+
         Self::draw_eye(
-            &mut self.left,
+            &self.left,
+            &self.left_lids,
+            &self.left_highlights,
+            self.clip_pupils,
             display,
-            &style.left_eye_inner,
-            &style.left_eye_outer,
-            &style.left_eye_line,
+            &left,
         )?;
         Self::draw_eye(
-            &mut self.right,
+            &self.right,
+            &self.right_lids,
+            &self.right_highlights,
+            self.clip_pupils,
             display,
-            &style.right_eye_inner,
-            &style.right_eye_outer,
-            &style.right_eye_line,
+            &right,
         )
     }
 
     fn draw_eye<D: DrawTargetExt>(
-        eye: &mut KerfurEyeType,
+        eye: &KerfurEyeType,
+        lids: &KerfurEyelids,
+        highlights: &KerfurHighlights,
+        clip_pupil: bool,
         display: &mut D,
-        inner: &PrimitiveStyle<D::Color>,
-        outer: &PrimitiveStyle<D::Color>,
-        line: &PrimitiveStyle<D::Color>,
+        style: &EyeStyle<'_, D::Color>,
+    ) -> Result<(), D::Error> {
+        // Only eyes with an outline can be covered by eyelids
+        if matches!(eye, KerfurEyeType::Ellipse(..) | KerfurEyeType::Arrow(..)) && !lids.is_open() {
+            let mut display = Masked::new(display, lids.mask(&eye.bounding_box()));
+            Self::draw_shape(eye, highlights, clip_pupil, &mut display, style)
+        } else {
+            Self::draw_shape(eye, highlights, clip_pupil, display, style)
+        }
+    }
+
+    fn draw_shape<D: DrawTarget>(
+        eye: &KerfurEyeType,
+        highlights: &KerfurHighlights,
+        clip_pupil: bool,
+        display: &mut D,
+        style: &EyeStyle<'_, D::Color>,
     ) -> Result<(), D::Error> {
         match eye {
+            // Keep the pupil and highlights inside the outer shape, if enabled
             KerfurEyeType::Ellipse(ellipse_a, ellipse_b) => {
-                ellipse_a.draw_styled(outer, display)?;
-                ellipse_b.draw_styled(inner, display)
+                ellipse_a.draw_styled(style.outer, display)?;
+                if clip_pupil {
+                    let mut display = Masked::new(display, |point| ellipse_a.contains(point));
+                    Self::draw_pupil(ellipse_b, highlights, &mut display, style)
+                } else {
+                    Self::draw_pupil(ellipse_b, highlights, display, style)
+                }
             }
             KerfurEyeType::Arrow(sector_a, sector_b) => {
-                sector_a.into_sector().draw_styled(outer, display)?;
-                sector_b.into_sector().draw_styled(inner, display)
+                let sector_a = sector_a.into_sector();
+                sector_a.draw_styled(style.outer, display)?;
+                if clip_pupil {
+                    let mut display = Masked::new(display, |point| sector_a.contains(point));
+                    sector_b.into_sector().draw_styled(style.inner, &mut display)
+                } else {
+                    sector_b.into_sector().draw_styled(style.inner, display)
+                }
             }
-            KerfurEyeType::Line(eye) => eye.draw_styled(line, display),
-            KerfurEyeType::Swirl(swirl) => swirl.draw_styled(outer, display),
+            KerfurEyeType::Line(eye) => eye.draw_styled(style.line, display),
+            KerfurEyeType::Swirl(swirl) => swirl.draw_styled(style.line, display),
+            KerfurEyeType::Heart(heart) => heart.draw_styled(style.outer, display),
+            KerfurEyeType::Star(star) => star.draw_styled(style.outer, display),
+            KerfurEyeType::Cross(cross) => cross.draw_styled(style.line, display),
+            KerfurEyeType::Caret(caret) => caret.draw_styled(style.line, display),
         }
     }
 
-    pub(super) fn interpolate(&mut self, other: &Self, tick: f32) {
-        Self::interpolate_eye(&mut self.left, &other.left, tick);
-        Self::interpolate_eye(&mut self.right, &other.right, tick);
+    fn draw_pupil<D: DrawTarget>(
+        pupil: &Ellipse,
+        highlights: &KerfurHighlights,
+        display: &mut D,
+        style: &EyeStyle<'_, D::Color>,
+    ) -> Result<(), D::Error> {
+        pupil.draw_styled(style.inner, display)?;
+        highlights.draw(pupil.center(), display, style.highlight)
+    }
+
+    pub(super) fn layout(&mut self, layout: &KerfurLayout) {
+        Self::layout_eye(&mut self.left, layout);
+        Self::layout_eye(&mut self.right, layout);
+        self.left_lids.layout(layout);
+        self.right_lids.layout(layout);
+        self.left_highlights.layout(layout);
+        self.right_highlights.layout(layout);
     }
 
-    fn interpolate_eye(a: &mut KerfurEyeType, b: &KerfurEyeType, tick: f32) {
+    fn layout_eye(eye: &mut KerfurEyeType, layout: &KerfurLayout) {
+        match eye {
+            KerfurEyeType::Ellipse(ellipse_a, ellipse_b) => {
+                let rect_a = layout.rectangle(ellipse_a.bounding_box());
+                *ellipse_a = Ellipse::new(rect_a.top_left, rect_a.size);
+                let rect_b = layout.rectangle(ellipse_b.bounding_box());
+                *ellipse_b = Ellipse::new(rect_b.top_left, rect_b.size);
+            }
+            KerfurEyeType::Arrow(sector_a, sector_b) => {
+                layout_sector(sector_a, layout);
+                layout_sector(sector_b, layout);
+            }
+            KerfurEyeType::Line(line) => layout_line(line, layout),
+            KerfurEyeType::Swirl(swirl) => {
+                layout_circle(&mut swirl.circle.top_left, &mut swirl.circle.diameter, layout);
+                swirl.angle = layout.angle(swirl.angle);
+            }
+            KerfurEyeType::Heart(Heart { rectangle })
+            | KerfurEyeType::Star(Star { rectangle })
+            | KerfurEyeType::Cross(Cross { rectangle })
+            | KerfurEyeType::Caret(Caret { rectangle }) => {
+                *rectangle = layout.rectangle(*rectangle);
+            }
+        }
+    }
+
+    pub(super) fn translate(&mut self, by: Point) {
+        self.left.translate_mut(by);
+        self.right.translate_mut(by);
+    }
+
+    /// Move the pupils of any [`KerfurEyeType::Ellipse`] eyes.
+    ///
+    /// The pupils are kept inside the outer ellipse.
+    pub(super) fn look(&mut self, by: Point) {
+        for eye in [&mut self.left, &mut self.right] {
+            if let KerfurEyeType::Ellipse(outer, inner) = eye {
+                let offset = saturating_add(inner.center() - outer.center(), by);
+                *inner = Ellipse::with_center(
+                    outer.center() + clamp_pupil(outer, inner, offset),
+                    inner.size,
+                );
+            }
+        }
+    }
+
+    /// Point the pupils of any [`KerfurEyeType::Ellipse`] eyes at a target.
+    ///
+    /// If `converge` is `true` each eye looks at the target separately,
+    /// otherwise both eyes look in the same direction from between the eyes.
+    pub(super) fn look_at(&mut self, target: Point, converge: bool) {
+        let between = self.left.bounding_box().center() + self.right.bounding_box().center();
+        let between = Point::new(between.x / 2, between.y / 2);
+
+        for eye in [&mut self.left, &mut self.right] {
+            if let KerfurEyeType::Ellipse(outer, inner) = eye {
+                let from = if converge { outer.center() } else { between };
+                let offset = scale_point(saturating_sub(target, from), GAZE_SCALE);
+                *inner = Ellipse::with_center(
+                    outer.center() + clamp_pupil(outer, inner, offset),
+                    inner.size,
+                );
+            }
+        }
+    }
+
+    /// Close any [`KerfurEyeType::Ellipse`] eyes by the given amount.
+    ///
+    /// An `amount` of `1.0` fully closes the eyes into lines.
+    pub(super) fn close(&mut self, amount: f32) {
+        for eye in [&mut self.left, &mut self.right] {
+            if let KerfurEyeType::Ellipse(outer, _) = eye {
+                let (center, width) = (outer.center(), outer.size.width.cast_signed() / 2);
+                let line = Line::new(center - Point::new(width, 0), center + Point::new(width, 0));
+                *eye = Self::interpolate_eye(eye, &KerfurEyeType::Line(line), amount);
+            }
+        }
+    }
+
+    /// Get the distance each eye, set of lids and set of highlights moves.
+    pub(super) fn distances(a: &Self, b: &Self) -> [f32; Self::PARTS] {
+        let (left, right) = (b.left.bounding_box().size, b.right.bounding_box().size);
+        [
+            Self::distance_eye(&a.left, &b.left),
+            Self::distance_eye(&a.right, &b.right),
+            KerfurEyelids::distance(&a.left_lids, &b.left_lids, left),
+            KerfurEyelids::distance(&a.right_lids, &b.right_lids, right),
+            KerfurHighlights::distance(&a.left_highlights, &b.left_highlights),
+            KerfurHighlights::distance(&a.right_highlights, &b.right_highlights),
+        ]
+    }
+
+    fn distance_eye(a: &KerfurEyeType, b: &KerfurEyeType) -> f32 {
+        match (a, b) {
+            (KerfurEyeType::Ellipse(a1, a2), KerfurEyeType::Ellipse(b1, b2)) => {
+                distance_ellipse(a1, b1).max(distance_ellipse(a2, b2))
+            }
+            (KerfurEyeType::Line(a), KerfurEyeType::Line(b)) => distance_line(a, b),
+            (KerfurEyeType::Arrow(a1, a2), KerfurEyeType::Arrow(b1, b2)) => {
+                distance_sector(a1, b1).max(distance_sector(a2, b2))
+            }
+            (KerfurEyeType::Swirl(a), KerfurEyeType::Swirl(b)) => {
+                distance_point(a.circle.top_left, b.circle.top_left)
+                    .max(distance_u32(a.circle.diameter, b.circle.diameter))
+                    .max(distance_angle(a.angle, b.angle, a.circle.diameter.max(b.circle.diameter)))
+            }
+            // Other eye shapes move between their bounding boxes
+            (a, b) => distance_rectangle(&a.bounding_box(), &b.bounding_box()),
+        }
+    }
+
+    /// Interpolate each part of the eyes by its own progress,
+    /// in the same order as [`EyeState::distances`].
+    ///
+    /// The spin and pupil clipping change along with the left eye.
+    pub(super) fn interpolate(a: &Self, b: &Self, t: [f32; Self::PARTS]) -> Self {
+        let [left, right, left_lids, right_lids, left_highlights, right_highlights] = t;
+        Self {
+            left: Self::interpolate_eye(&a.left, &b.left, left),
+            right: Self::interpolate_eye(&a.right, &b.right, right),
+            left_lids: KerfurEyelids::interpolate(&a.left_lids, &b.left_lids, left_lids),
+            right_lids: KerfurEyelids::interpolate(&a.right_lids, &b.right_lids, right_lids),
+            left_highlights: KerfurHighlights::interpolate(
+                &a.left_highlights,
+                &b.left_highlights,
+                left_highlights,
+            ),
+            right_highlights: KerfurHighlights::interpolate(
+                &a.right_highlights,
+                &b.right_highlights,
+                right_highlights,
+            ),
+            spin: if left > 0. { b.spin } else { a.spin },
+            clip_pupils: if left > 0. { b.clip_pupils } else { a.clip_pupils },
+        }
+    }
+
+    fn interpolate_eye(a: &KerfurEyeType, b: &KerfurEyeType, t: f32) -> KerfurEyeType {
         match (a, b) {
             (KerfurEyeType::Ellipse(a1, a2), KerfurEyeType::Ellipse(b1, b2)) => {
-                interp_size(&mut a1.size, b1.size, tick);
-                interp_point(&mut a1.top_left, b1.top_left, tick);
-                interp_size(&mut a2.size, b2.size, tick);
-                interp_point(&mut a2.top_left, b2.top_left, tick);
+                KerfurEyeType::Ellipse(lerp_ellipse(a1, b1, t), lerp_ellipse(a2, b2, t))
             }
             (KerfurEyeType::Line(a), KerfurEyeType::Line(b)) => {
-                interp_line(a, b, tick);
+                KerfurEyeType::Line(lerp_line(a, b, t))
             }
             (KerfurEyeType::Arrow(a1, a2), KerfurEyeType::Arrow(b1, b2)) => {
-                interp_point(&mut a1.top_left, b1.top_left, tick);
-                interp_angle(&mut a1.angle_start, b1.angle_start, tick);
-                interp_angle(&mut a1.angle_sweep, b1.angle_sweep, tick);
-                interp_diameter(&mut a1.diameter, b1.diameter, tick);
-                interp_point(&mut a2.top_left, b2.top_left, tick);
-                interp_angle(&mut a2.angle_start, b2.angle_start, tick);
-                interp_angle(&mut a2.angle_sweep, b2.angle_sweep, tick);
-                interp_diameter(&mut a2.diameter, b2.diameter, tick);
+                KerfurEyeType::Arrow(lerp_sector(a1, b1, t), lerp_sector(a2, b2, t))
             }
-            (KerfurEyeType::Swirl(a), KerfurEyeType::Swirl(b)) => {
-                interp_point(&mut a.circle.top_left, b.circle.top_left, tick);
+            (KerfurEyeType::Swirl(a), KerfurEyeType::Swirl(b)) => KerfurEyeType::Swirl(Swirl {
+                angle: lerp_angle(a.angle, b.angle, t),
+                circle: Circle::new(
+                    lerp_point(a.circle.top_left, b.circle.top_left, t),
+                    lerp_u32(a.circle.diameter, b.circle.diameter, t),
+                ),
+            }),
+            // Squash ellipses into a flat ellipse, then swap to the line
+            (KerfurEyeType::Ellipse(..), KerfurEyeType::Line(line)) => {
+                if t >= 1. {
+                    *b
+                } else {
+                    Self::interpolate_eye(a, &line_as_ellipse(line), t)
+                }
+            }
+            (KerfurEyeType::Line(line), KerfurEyeType::Ellipse(..)) => {
+                if t <= 0. {
+                    *a
+                } else {
+                    Self::interpolate_eye(&line_as_ellipse(line), b, t)
+                }
+            }
+            // Fold ellipses into sectors, starting from a full circle
+            (KerfurEyeType::Ellipse(outer, inner), KerfurEyeType::Arrow(..)) => {
+                if t <= 0. {
+                    *a
+                } else {
+                    Self::interpolate_eye(&ellipse_as_arrow(outer, inner, b), b, t)
+                }
+            }
+            (KerfurEyeType::Arrow(..), KerfurEyeType::Ellipse(outer, inner)) => {
+                if t >= 1. {
+                    *b
+                } else {
+                    Self::interpolate_eye(a, &ellipse_as_arrow(outer, inner, a), t)
+                }
+            }
+            // Open lines into ellipses before folding them into sectors
+            (KerfurEyeType::Line(_), KerfurEyeType::Arrow(outer, inner))
+            | (KerfurEyeType::Arrow(outer, inner), KerfurEyeType::Line(_)) => {
+                let ellipse = arrow_as_ellipse(outer, inner);
+                if t < 0.5 {
+                    Self::interpolate_eye(a, &ellipse, t * 2.)
+                } else {
+                    Self::interpolate_eye(&ellipse, b, t * 2. - 1.)
+                }
+            }
+            (KerfurEyeType::Heart(a), KerfurEyeType::Heart(b)) => KerfurEyeType::Heart(Heart {
+                rectangle: lerp_rectangle(&a.rectangle, &b.rectangle, t),
+            }),
+            (KerfurEyeType::Star(a), KerfurEyeType::Star(b)) => KerfurEyeType::Star(Star {
+                rectangle: lerp_rectangle(&a.rectangle, &b.rectangle, t),
+            }),
+            (KerfurEyeType::Cross(a), KerfurEyeType::Cross(b)) => KerfurEyeType::Cross(Cross {
+                rectangle: lerp_rectangle(&a.rectangle, &b.rectangle, t),
+            }),
+            (KerfurEyeType::Caret(a), KerfurEyeType::Caret(b)) => KerfurEyeType::Caret(Caret {
+                rectangle: lerp_rectangle(&a.rectangle, &b.rectangle, t),
+            }),
+            // Shrink the current eye away, then grow the new eye in its place
+            _ => {
+                let center = Point::new(
+                    i32::midpoint(a.bounding_box().center().x, b.bounding_box().center().x),
+                    i32::midpoint(a.bounding_box().center().y, b.bounding_box().center().y),
+                );
+                if t < 0.5 {
+                    Self::interpolate_eye(a, &a.collapsed(center), t * 2.)
+                } else {
+                    Self::interpolate_eye(&b.collapsed(center), b, t * 2. - 1.)
+                }
+            }
+        }
+    }
+
+    pub(super) fn rotate_swirls(&mut self, by: f32) {
+        for eye in [&mut self.left, &mut self.right] {
+            if let KerfurEyeType::Swirl(swirl) = eye {
+                swirl.angle = wrap_angle(swirl.angle + by);
             }
-            // Immediately use the new eye shape and snap to the final position
-            (current, other) => *current = *other,
         }
     }
 }
 
-// Interpolate the diameter of an arc.
-// TODO: Stop being lazy and make a proper function.
-fn interp_diameter(a: &mut u32, b: u32, tick: f32) {
-    let mut size = Size::new(*a, 0);
-    interp_size(&mut size, Size::new(b, 0), tick);
-    *a = size.width;
+/// How far the pupils move toward a target, relative to the target's distance.
+const GAZE_SCALE: f32 = 0.25;
+
+// Clamp a pupil offset so the pupil stays inside the outer ellipse.
+#[expect(clippy::cast_precision_loss, reason = "Sizes will never be that large")]
+#[expect(clippy::cast_possible_truncation, reason = "Offsets will never be that large")]
+fn clamp_pupil(outer: &Ellipse, inner: &Ellipse, offset: Point) -> Point {
+    // The pupil center can move within a smaller ellipse
+    let travel_x = outer.size.width.saturating_sub(inner.size.width) as f32 / 2.;
+    let travel_y = outer.size.height.saturating_sub(inner.size.height) as f32 / 2.;
+    let (x, y) = (offset.x as f32, offset.y as f32);
+
+    let scaled_x = if travel_x > 0. { x / travel_x } else { 0. };
+    let scaled_y = if travel_y > 0. { y / travel_y } else { 0. };
+    let length = sqrtf(scaled_x * scaled_x + scaled_y * scaled_y);
+
+    // Truncate toward zero, so the pupil never rounds outside
+    let scale = if length > 1. { 1. / length } else { 1. };
+    Point::new((scaled_x * travel_x * scale) as i32, (scaled_y * travel_y * scale) as i32)
 }
 
-// -------------------------------------------------------------------------------------------------
+// Get the offset from the top-left corner of a shape to its center,
+// rounded down the same way as `Rectangle::center`.
+const fn center_offset(size: Size) -> Point {
+    Point::new(
+        (size.width.saturating_sub(1) / 2).cast_signed(),
+        (size.height.saturating_sub(1) / 2).cast_signed(),
+    )
+}
 
-#[derive(Clone, Copy, PartialEq, Eq)]
-pub(super) struct EyebrowState {
-    pub(super) left: Line,
-    pub(super) right: Line,
+// Scale a point by a factor, rounding to the nearest pixel.
+#[expect(clippy::cast_precision_loss, reason = "Positions will never be that large")]
+#[expect(clippy::cast_possible_truncation, reason = "Positions will never be that large")]
+fn scale_point(point: Point, scale: f32) -> Point {
+    Point::new(roundf(point.x as f32 * scale) as i32, roundf(point.y as f32 * scale) as i32)
 }
 
-impl EyebrowState {
-    pub(super) fn draw<D: DrawTargetExt>(
-        &mut self,
-        display: &mut D,
-        style: &KerfurStyle<D::Color>,
-    ) -> Result<(), D::Error> {
-        self.left.draw_styled(&style.left_eyebrow, display)?;
-        self.right.draw_styled(&style.right_eyebrow, display)?;
-        Ok(())
-    }
+// Scale a length by a factor, rounding to the nearest pixel.
+#[expect(clippy::cast_precision_loss, reason = "Sizes will never be that large")]
+#[expect(clippy::cast_possible_truncation, reason = "Sizes will never be that large")]
+#[expect(clippy::cast_sign_loss, reason = "Sizes are clamped to zero")]
+fn scale_u32(length: u32, scale: f32) -> u32 { roundf(length as f32 * scale).max(0.) as u32 }
+
+fn scale_size(size: Size, scale: f32) -> Size {
+    Size::new(scale_u32(size.width, scale), scale_u32(size.height, scale))
+}
 
-    pub(super) fn interpolate(&mut self, other: &Self, tick: f32) {
-        interp_line(&mut self.left, &other.left, tick);
-        interp_line(&mut self.right, &other.right, tick);
+/// The height of the flat ellipse a [`KerfurEyeType::Line`] morphs through.
+///
+/// Matches the width of the eye lines in the default styles.
+const LINE_HEIGHT: u32 = 16;
+
+impl KerfurEyeType {
+    /// Returns the same type of eye, shrunk down to a single point.
+    fn collapsed(&self, center: Point) -> Self {
+        match *self {
+            KerfurEyeType::Ellipse(..) => KerfurEyeType::Ellipse(
+                Ellipse::with_center(center, Size::zero()),
+                Ellipse::with_center(center, Size::zero()),
+            ),
+            KerfurEyeType::Arrow(outer, inner) => KerfurEyeType::Arrow(
+                ConstSector::with_center(center, 0, outer.angle_start, outer.angle_sweep),
+                ConstSector::with_center(center, 0, inner.angle_start, inner.angle_sweep),
+            ),
+            KerfurEyeType::Line(_) => KerfurEyeType::Line(Line::new(center, center)),
+            KerfurEyeType::Swirl(swirl) => {
+                KerfurEyeType::Swirl(Swirl::with_center(center, swirl.angle, 0))
+            }
+            KerfurEyeType::Heart(_) => {
+                KerfurEyeType::Heart(Heart::with_center(center, Size::zero()))
+            }
+            KerfurEyeType::Star(_) => KerfurEyeType::Star(Star::with_center(center, Size::zero())),
+            KerfurEyeType::Cross(_) => {
+                KerfurEyeType::Cross(Cross::with_center(center, Size::zero()))
+            }
+            KerfurEyeType::Caret(_) => {
+                KerfurEyeType::Caret(Caret::with_center(center, Size::zero()))
+            }
+        }
     }
 }
+
+// A flat ellipse covering a line, with an empty pupil.
+fn line_as_ellipse(line: &Line) -> KerfurEyeType {
+    let (center, delta) = (line.midpoint(), line.delta());
+    let size = Size::new(delta.x.unsigned_abs(), delta.y.unsigned_abs().max(LINE_HEIGHT));
+    KerfurEyeType::Ellipse(
+        Ellipse::with_center(center, size),
+        Ellipse::with_center(center, Size::zero()),
+    )
+}
+
+// A pair of full-circle sectors covering a pair of ellipses.
+//
+// The sectors are angled to match the `like` eye, so they fold evenly into it.
+fn ellipse_as_arrow(outer: &Ellipse, inner: &Ellipse, like: &KerfurEyeType) -> KerfurEyeType {
+    let KerfurEyeType::Arrow(like_outer, like_inner) = like else { return *like };
+
+    let full_sector = |ellipse: &Ellipse, like: &ConstSector| {
+        let diameter = ellipse.size.width.max(ellipse.size.height);
+        let middle = like.angle_start + like.angle_sweep / 2.;
+        let sweep = TAU.copysign(like.angle_sweep);
+        ConstSector::with_center(ellipse.center(), diameter, middle - sweep / 2., sweep)
+    };
+
+    KerfurEyeType::Arrow(full_sector(outer, like_outer), full_sector(inner, like_inner))
+}
+
+// A pair of ellipses covering the circles supporting a pair of sectors.
+fn arrow_as_ellipse(outer: &ConstSector, inner: &ConstSector) -> KerfurEyeType {
+    KerfurEyeType::Ellipse(
+        Ellipse::new(outer.top_left, Size::new_equal(outer.diameter)),
+        Ellipse::new(inner.top_left, Size::new_equal(inner.diameter)),
+    )
+}
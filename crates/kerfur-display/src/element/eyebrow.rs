@@ -0,0 +1,227 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Line, Rectangle, StyledDrawable},
+};
+
+use crate::{
+    KerfurLayout, KerfurStyle,
+    element::{
+        distance_arc, distance_line, distance_point, layout_arc, layout_line, lerp, lerp_arc,
+        lerp_line, lerp_point, saturating_add,
+    },
+    math::{atan2f, cosf, roundf, sinf, sqrtf},
+    primitive::{ConstArc, mirror_line},
+};
+
+#[derive(Clone, Copy, PartialEq)]
+pub(super) struct EyebrowState {
+    pub(super) left: KerfurEyebrowType,
+    pub(super) right: KerfurEyebrowType,
+}
+
+/// The type of eyebrow to display
+///
+/// Both types are drawn with the eyebrow styles, so the style sets how thick
+/// each eyebrow is.
+#[derive(Clone, Copy, PartialEq)]
+pub enum KerfurEyebrowType {
+    /// A straight eyebrow
+    Line(Line),
+    /// A curved eyebrow
+    Arc(ConstArc),
+}
+
+impl KerfurEyebrowType {
+    /// Returns the eyebrow mirrored across the face's vertical center line.
+    ///
+    /// See [`primitive`](crate::primitive) for how each shape is mirrored.
+    #[must_use]
+    pub const fn mirrored(self) -> Self {
+        match self {
+            KerfurEyebrowType::Line(line) => KerfurEyebrowType::Line(mirror_line(line)),
+            KerfurEyebrowType::Arc(arc) => KerfurEyebrowType::Arc(arc.mirrored()),
+        }
+    }
+}
+
+impl Dimensions for KerfurEyebrowType {
+    fn bounding_box(&self) -> Rectangle {
+        match self {
+            KerfurEyebrowType::Line(line) => line.bounding_box(),
+            KerfurEyebrowType::Arc(arc) => arc.into_arc().bounding_box(),
+        }
+    }
+}
+
+impl Transform for KerfurEyebrowType {
+    fn translate(&self, by: Point) -> Self {
+        let mut eyebrow = *self;
+        eyebrow.translate_mut(by);
+        eyebrow
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        match self {
+            KerfurEyebrowType::Line(line) => {
+                line.start = saturating_add(line.start, by);
+                line.end = saturating_add(line.end, by);
+            }
+            KerfurEyebrowType::Arc(arc) => arc.top_left = saturating_add(arc.top_left, by),
+        }
+        self
+    }
+}
+
+impl EyebrowState {
+    /// The number of parts of the eyebrows that move separately.
+    pub(super) const PARTS: usize = 2;
+
+    pub(super) fn draw<D: DrawTargetExt>(
+        &mut self,
+        display: &mut D,
+        style: &KerfurStyle<D::Color>,
+    ) -> Result<(), D::Error> {
+        for (eyebrow, style) in
+            [(&self.left, &style.left_eyebrow), (&self.right, &style.right_eyebrow)]
+        {
+            match eyebrow {
+                KerfurEyebrowType::Line(line) => line.draw_styled(style, display)?,
+                KerfurEyebrowType::Arc(arc) => arc.into_arc().draw_styled(style, display)?,
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn translate(&mut self, by: Point) {
+        self.left.translate_mut(by);
+        self.right.translate_mut(by);
+    }
+
+    /// Get the distance each eyebrow moves.
+    pub(super) fn distances(a: &Self, b: &Self) -> [f32; Self::PARTS] {
+        [Self::distance_eyebrow(&a.left, &b.left), Self::distance_eyebrow(&a.right, &b.right)]
+    }
+
+    fn distance_eyebrow(a: &KerfurEyebrowType, b: &KerfurEyebrowType) -> f32 {
+        match (a, b) {
+            (KerfurEyebrowType::Line(a), KerfurEyebrowType::Line(b)) => distance_line(a, b),
+            (KerfurEyebrowType::Arc(a), KerfurEyebrowType::Arc(b)) => distance_arc(a, b),
+            // Lines bend into arcs, moving their ends and bulging in the middle
+            (KerfurEyebrowType::Line(line), KerfurEyebrowType::Arc(arc))
+            | (KerfurEyebrowType::Arc(arc), KerfurEyebrowType::Line(line)) => {
+                let (start, end, _) = arc_ends(arc, line);
+                distance_point(line.start, start)
+                    .max(distance_point(line.end, end))
+                    .max(arc_bulge(arc))
+            }
+        }
+    }
+
+    /// Interpolate each eyebrow by its own progress.
+    pub(super) fn interpolate(a: &Self, b: &Self, [left, right]: [f32; Self::PARTS]) -> Self {
+        Self {
+            left: Self::interpolate_eyebrow(&a.left, &b.left, left),
+            right: Self::interpolate_eyebrow(&a.right, &b.right, right),
+        }
+    }
+
+    fn interpolate_eyebrow(
+        a: &KerfurEyebrowType,
+        b: &KerfurEyebrowType,
+        t: f32,
+    ) -> KerfurEyebrowType {
+        match (a, b) {
+            (KerfurEyebrowType::Line(a), KerfurEyebrowType::Line(b)) => {
+                KerfurEyebrowType::Line(lerp_line(a, b, t))
+            }
+            (KerfurEyebrowType::Arc(a), KerfurEyebrowType::Arc(b)) => {
+                KerfurEyebrowType::Arc(lerp_arc(a, b, t))
+            }
+            // Bend lines into arcs with the same ends, then swap to the arc
+            (KerfurEyebrowType::Line(line), KerfurEyebrowType::Arc(arc)) => {
+                if t >= 1. {
+                    *b
+                } else {
+                    bend_line(line, arc, t)
+                }
+            }
+            (KerfurEyebrowType::Arc(arc), KerfurEyebrowType::Line(line)) => {
+                if t <= 0. {
+                    *a
+                } else {
+                    bend_line(line, arc, 1. - t)
+                }
+            }
+        }
+    }
+
+    pub(super) fn layout(&mut self, layout: &KerfurLayout) {
+        for eyebrow in [&mut self.left, &mut self.right] {
+            match eyebrow {
+                KerfurEyebrowType::Line(line) => layout_line(line, layout),
+                KerfurEyebrowType::Arc(arc) => layout_arc(arc, layout),
+            }
+        }
+    }
+}
+
+/// The smallest sweep drawn as an arc while bending a line into an arc.
+///
+/// Arcs with smaller sweeps are nearly flat, so they are drawn as a line
+/// instead of an arc on a very large circle.
+const MIN_SWEEP: f32 = 0.2;
+
+// The ends of an arc, ordered to match the closest ends of a line,
+// along with the sweep from the first end to the second.
+#[expect(clippy::cast_precision_loss, reason = "Diameters will never be that large")]
+#[expect(clippy::cast_possible_truncation, reason = "Positions will never be that large")]
+fn arc_ends(arc: &ConstArc, line: &Line) -> (Point, Point, f32) {
+    let (center, radius) = (arc.center(), arc.diameter as f32 / 2.);
+    let point = |angle: f32| {
+        center
+            + Point::new(roundf(cosf(angle) * radius) as i32, roundf(sinf(angle) * radius) as i32)
+    };
+    let (start, end) = (point(arc.angle_start), point(arc.angle_start + arc.angle_sweep));
+
+    let forward = distance_point(line.start, start) + distance_point(line.end, end);
+    let backward = distance_point(line.start, end) + distance_point(line.end, start);
+    if backward < forward { (end, start, -arc.angle_sweep) } else { (start, end, arc.angle_sweep) }
+}
+
+// How far the middle of an arc bulges away from the line between its ends.
+#[expect(clippy::cast_precision_loss, reason = "Diameters will never be that large")]
+fn arc_bulge(arc: &ConstArc) -> f32 {
+    arc.diameter as f32 / 2. * (1. - cosf(arc.angle_sweep.abs().min(core::f32::consts::TAU) / 2.))
+}
+
+// Bend a line part of the way into an arc.
+//
+// The ends move toward the ends of the arc while the sweep grows,
+// so the curve stays between the ends the whole way.
+#[expect(clippy::cast_precision_loss, reason = "Positions will never be that large")]
+#[expect(clippy::cast_possible_truncation, reason = "Positions will never be that large")]
+#[expect(clippy::cast_sign_loss, reason = "Diameters are never negative")]
+fn bend_line(line: &Line, arc: &ConstArc, t: f32) -> KerfurEyebrowType {
+    let (arc_start, arc_end, arc_sweep) = arc_ends(arc, line);
+    let (start, end) = (lerp_point(line.start, arc_start, t), lerp_point(line.end, arc_end, t));
+    let sweep = lerp(0., arc_sweep, t);
+    if sweep.abs() < MIN_SWEEP {
+        return KerfurEyebrowType::Line(Line::new(start, end));
+    }
+
+    // Find the circle through both ends with the given sweep between them
+    let (x, y) = ((end.x - start.x) as f32, (end.y - start.y) as f32);
+    let chord = sqrtf(x * x + y * y);
+    if chord <= 0. {
+        return KerfurEyebrowType::Line(Line::new(start, end));
+    }
+    let half = sweep / 2.;
+    let offset = chord / 2. * cosf(half) / sinf(half);
+    let center_x = (start.x + end.x) as f32 / 2. - y / chord * offset;
+    let center_y = (start.y + end.y) as f32 / 2. + x / chord * offset;
+    let radius = chord / 2. / sinf(half).abs();
+
+    let angle = atan2f(start.y as f32 - center_y, start.x as f32 - center_x);
+    let center = Point::new(roundf(center_x) as i32, roundf(center_y) as i32);
+    KerfurEyebrowType::Arc(ConstArc::with_center(center, roundf(radius * 2.) as u32, angle, sweep))
+}
@@ -0,0 +1,167 @@
+use core::f32::consts::FRAC_PI_3;
+
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+use crate::{
+    KerfurLayout,
+    element::lerp,
+    math::{cosf, sinf},
+};
+
+/// The eyelids covering an eye.
+///
+/// Each lid covers part of the eye, measured as a fraction of the eye's
+/// height from the top or bottom, and can be tilted to make the eye look
+/// angry or suspicious.
+///
+/// Lids clip [`KerfurEyeType::Ellipse`](crate::element::KerfurEyeType::Ellipse)
+/// and [`KerfurEyeType::Arrow`](crate::element::KerfurEyeType::Arrow) eyes
+/// as they are drawn, other eyes are never covered.
+///
+/// ```rust
+/// use core::f32::consts::PI;
+///
+/// use kerfur_display::element::KerfurEyelids;
+///
+/// // Half-closed, with the top lid sloping down toward the nose
+/// let lids = KerfurEyelids::new(0.5, 0.).with_top_angle(PI / 12.);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct KerfurEyelids {
+    top: f32,
+    bottom: f32,
+    top_angle: f32,
+    bottom_angle: f32,
+}
+
+impl KerfurEyelids {
+    /// The steepest angle a lid can be tilted by, in radians.
+    ///
+    /// Steeper lids would cover the whole side of an eye.
+    pub const MAX_ANGLE: f32 = FRAC_PI_3;
+    /// Fully open eyelids, which don't cover the eye.
+    pub const OPEN: Self = Self::new(0., 0.);
+
+    /// Create a new set of [`KerfurEyelids`].
+    ///
+    /// `top` and `bottom` are the fraction of the eye each lid covers,
+    /// where `0.0` is fully open and `0.5` reaches the middle of the eye.
+    #[inline]
+    #[must_use]
+    pub const fn new(top: f32, bottom: f32) -> Self {
+        Self { top, bottom, top_angle: 0., bottom_angle: 0. }
+    }
+
+    /// Tilt the top lid by the given angle, in radians.
+    ///
+    /// Positive angles tilt the lid clockwise around the middle of the eye.
+    /// The angle is clamped to [`KerfurEyelids::MAX_ANGLE`] either way.
+    #[inline]
+    #[must_use]
+    pub const fn with_top_angle(mut self, angle: f32) -> Self {
+        self.top_angle = angle.clamp(-Self::MAX_ANGLE, Self::MAX_ANGLE);
+        self
+    }
+
+    /// Tilt the bottom lid by the given angle, in radians.
+    ///
+    /// Positive angles tilt the lid clockwise around the middle of the eye.
+    /// The angle is clamped to [`KerfurEyelids::MAX_ANGLE`] either way.
+    #[inline]
+    #[must_use]
+    pub const fn with_bottom_angle(mut self, angle: f32) -> Self {
+        self.bottom_angle = angle.clamp(-Self::MAX_ANGLE, Self::MAX_ANGLE);
+        self
+    }
+
+    /// Get the fraction of the eye the top lid covers.
+    #[inline]
+    #[must_use]
+    pub const fn top(&self) -> f32 { self.top }
+
+    /// Get the fraction of the eye the bottom lid covers.
+    #[inline]
+    #[must_use]
+    pub const fn bottom(&self) -> f32 { self.bottom }
+
+    /// Get the angle of the top lid, in radians.
+    #[inline]
+    #[must_use]
+    pub const fn top_angle(&self) -> f32 { self.top_angle }
+
+    /// Get the angle of the bottom lid, in radians.
+    #[inline]
+    #[must_use]
+    pub const fn bottom_angle(&self) -> f32 { self.bottom_angle }
+
+    /// Returns the eyelids mirrored across the face's vertical center line.
+    ///
+    /// This turns the lids of a left eye into the lids of a right eye.
+    #[inline]
+    #[must_use]
+    pub const fn mirrored(self) -> Self {
+        Self {
+            top: self.top,
+            bottom: self.bottom,
+            top_angle: -self.top_angle,
+            bottom_angle: -self.bottom_angle,
+        }
+    }
+
+    /// Tilt the lids along with the face.
+    pub(super) fn layout(&mut self, layout: &KerfurLayout) {
+        self.top_angle = layout.angle(self.top_angle);
+        self.bottom_angle = layout.angle(self.bottom_angle);
+    }
+
+    /// Returns `true` if neither lid covers the eye.
+    pub(super) fn is_open(&self) -> bool { self.top <= 0. && self.bottom <= 0. }
+
+    /// Get a mask for an eye with the given bounds,
+    /// which returns `true` for points between the lids.
+    #[expect(clippy::cast_precision_loss, reason = "Positions will never be that large")]
+    pub(super) fn mask(&self, bounds: &Rectangle) -> impl Fn(Point) -> bool + use<> {
+        let height = bounds.size.height as f32;
+        let center_x = bounds.top_left.x as f32 + bounds.size.width as f32 / 2.;
+
+        // Each lid is a line through the middle of the eye's width,
+        // which may have been tilted past the limit along with the face
+        let lid = |covered: f32, angle: f32, edge: i32, direction: f32| {
+            (covered > 0.).then(|| {
+                let y = edge as f32 + direction * covered * height;
+                let angle = angle.clamp(-Self::MAX_ANGLE, Self::MAX_ANGLE);
+                (y, sinf(angle) / cosf(angle))
+            })
+        };
+        let bottom_edge = bounds.top_left.y + bounds.size.height.cast_signed();
+        let top = lid(self.top, self.top_angle, bounds.top_left.y, 1.);
+        let bottom = lid(self.bottom, self.bottom_angle, bottom_edge, -1.);
+
+        move |point| {
+            let (x, y) = (point.x as f32 - center_x, point.y as f32);
+            top.is_none_or(|(top, slope)| y >= top + x * slope)
+                && bottom.is_none_or(|(bottom, slope)| y <= bottom + x * slope)
+        }
+    }
+
+    /// Get the furthest distance the edge of either lid moves
+    /// on an eye of the given size.
+    #[expect(clippy::cast_precision_loss, reason = "Sizes will never be that large")]
+    pub(super) fn distance(a: &Self, b: &Self, size: Size) -> f32 {
+        let (width, height) = (size.width as f32, size.height as f32);
+        ((b.top - a.top).abs() * height)
+            .max((b.bottom - a.bottom).abs() * height)
+            .max((b.top_angle - a.top_angle).abs() * width / 2.)
+            .max((b.bottom_angle - a.bottom_angle).abs() * width / 2.)
+    }
+
+    /// Interpolate between two sets of eyelids.
+    pub(super) fn interpolate(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            top: lerp(a.top, b.top, t),
+            bottom: lerp(a.bottom, b.bottom, t),
+            top_angle: lerp(a.top_angle, b.top_angle, t),
+            bottom_angle: lerp(a.bottom_angle, b.bottom_angle, t),
+        }
+    }
+}
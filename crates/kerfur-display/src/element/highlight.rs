@@ -0,0 +1,102 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Circle, PrimitiveStyle, StyledDrawable},
+};
+
+use crate::{
+    KerfurLayout,
+    element::{distance_point, distance_u32, lerp_point, lerp_u32},
+};
+
+/// The highlights shining on the pupil of an eye.
+///
+/// An eye can have up to two highlights, each a small circle positioned
+/// relative to the center of the pupil. The highlights move with the pupil,
+/// so they stay in place while the eyes look around.
+///
+/// Only [`KerfurEyeType::Ellipse`](crate::element::KerfurEyeType::Ellipse)
+/// eyes have highlights.
+///
+/// ```rust
+/// use embedded_graphics::prelude::Point;
+/// use kerfur_display::element::KerfurHighlights;
+///
+/// // A large highlight above and left of the pupil, and a small one below it
+/// let highlights =
+///     KerfurHighlights::new(Point::new(-16, -16), 24).with_second(Point::new(12, 14), 10);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct KerfurHighlights {
+    first: (Point, u32),
+    second: (Point, u32),
+}
+
+impl KerfurHighlights {
+    /// No highlights.
+    pub const NONE: Self = Self { first: (Point::zero(), 0), second: (Point::zero(), 0) };
+
+    /// Create a single highlight at an offset from the center of the pupil.
+    #[inline]
+    #[must_use]
+    pub const fn new(offset: Point, diameter: u32) -> Self {
+        Self { first: (offset, diameter), second: (Point::zero(), 0) }
+    }
+
+    /// Add a second highlight at an offset from the center of the pupil.
+    #[inline]
+    #[must_use]
+    pub const fn with_second(mut self, offset: Point, diameter: u32) -> Self {
+        self.second = (offset, diameter);
+        self
+    }
+
+    /// Returns the highlights mirrored across the face's vertical center line.
+    ///
+    /// This turns the highlights of a left eye into the highlights of a
+    /// right eye, so light seems to come from the opposite side.
+    #[inline]
+    #[must_use]
+    pub const fn mirrored(self) -> Self {
+        let ((first, first_diameter), (second, second_diameter)) = (self.first, self.second);
+        Self {
+            first: (Point::new(-first.x, first.y), first_diameter),
+            second: (Point::new(-second.x, second.y), second_diameter),
+        }
+    }
+
+    /// Draw the highlights around the given pupil center.
+    pub(super) fn draw<D: DrawTarget>(
+        &self,
+        pupil: Point,
+        display: &mut D,
+        style: &PrimitiveStyle<D::Color>,
+    ) -> Result<(), D::Error> {
+        for (offset, diameter) in [self.first, self.second] {
+            if diameter > 0 {
+                Circle::with_center(pupil + offset, diameter).draw_styled(style, display)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn layout(&mut self, layout: &KerfurLayout) {
+        for (offset, diameter) in [&mut self.first, &mut self.second] {
+            *offset = layout.vector(*offset);
+            *diameter = layout.length(*diameter);
+        }
+    }
+
+    pub(super) fn distance(a: &Self, b: &Self) -> f32 {
+        let distance = |(a_offset, a_diameter): (Point, u32), (b_offset, b_diameter)| {
+            distance_point(a_offset, b_offset).max(distance_u32(a_diameter, b_diameter))
+        };
+        distance(a.first, b.first).max(distance(a.second, b.second))
+    }
+
+    pub(super) fn interpolate(a: &Self, b: &Self, t: f32) -> Self {
+        let lerp = |(a_offset, a_diameter): (Point, u32), (b_offset, b_diameter)| {
+            (lerp_point(a_offset, b_offset, t), lerp_u32(a_diameter, b_diameter, t))
+        };
+        Self { first: lerp(a.first, b.first), second: lerp(a.second, b.second) }
+    }
+}
@@ -1,29 +1,53 @@
 //! TODO
-#![expect(clippy::many_single_char_names, reason = "Math")]
 
-use core::{
-    cmp::Ordering,
-    f32::consts::{FRAC_PI_2, FRAC_PI_4, FRAC_PI_6, PI},
-};
+use core::f32::consts::{FRAC_PI_2, FRAC_PI_4};
 
-use embedded_graphics::{prelude::*, primitives::Line};
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Ellipse, Line, Rectangle},
+};
 
 use crate::{
-    KerfurStyle,
-    primitive::{ConstArc, ConstSector},
+    KerfurExpression, KerfurLayout, KerfurStyle,
+    math::{angle_between, roundf, sqrtf},
+    primitive::{ConstArc, ConstSector, mirror_line},
 };
 
+mod clip;
+
 mod eye;
 pub use eye::KerfurEyeType;
 
+mod eyebrow;
+pub use eyebrow::KerfurEyebrowType;
+
+mod eyelid;
+pub use eyelid::KerfurEyelids;
+
+mod highlight;
+pub use highlight::KerfurHighlights;
+
 mod mouth;
+pub use mouth::KerfurMouthType;
 mod whisker;
 
+#[cfg(test)]
+mod tests;
+
+/// The number of parts of a face that can be interpolated separately.
+pub(crate) const PARTS: usize = eye::EyeState::PARTS
+    + eyebrow::EyebrowState::PARTS
+    + mouth::MouthState::PARTS
+    + whisker::WhiskerState::PARTS;
+
 /// A set of facial elements
+///
+/// All elements are positioned on a [`KerfurLayout::CANVAS`] sized canvas,
+/// and are scaled to fit the display when drawn.
 #[derive(Clone, Copy, PartialEq)]
 pub struct KerfurElements {
     eye: eye::EyeState,
-    eyebrow: eye::EyebrowState,
+    eyebrow: eyebrow::EyebrowState,
     mouth: mouth::MouthState,
     whisker: whisker::WhiskerState,
 }
@@ -43,16 +67,22 @@ impl KerfurElements {
             eye: eye::EyeState {
                 left: KerfurEyeType::NEUTRAL_LEFT,
                 right: KerfurEyeType::NEUTRAL_RIGHT,
+                left_lids: KerfurEyelids::OPEN,
+                right_lids: KerfurEyelids::OPEN,
+                left_highlights: KerfurHighlights::NONE,
+                right_highlights: KerfurHighlights::NONE,
+                spin: 0.,
+                clip_pupils: true,
             },
-            eyebrow: eye::EyebrowState {
-                left: Line::new(
+            eyebrow: eyebrow::EyebrowState {
+                left: KerfurEyebrowType::Line(Line::new(
                     Point::new(480 * 42 / 100, 480 * 29 / 100),
                     Point::new(480 * 35 / 100, 480 * 29 / 100),
-                ),
-                right: Line::new(
+                )),
+                right: KerfurEyebrowType::Line(Line::new(
                     Point::new(480 * 58 / 100, 480 * 29 / 100),
                     Point::new(480 * 65 / 100, 480 * 29 / 100),
-                ),
+                )),
             },
             mouth: mouth::MouthState {
                 nose: ConstSector::with_center(
@@ -61,14 +91,7 @@ impl KerfurElements {
                     3. * FRAC_PI_2 - FRAC_PI_4,
                     2. * FRAC_PI_4,
                 ),
-                mouth_left: ConstArc::with_center(Point::new(225, 480 * 59 / 100), 30, 0., PI),
-                mouth_right: ConstArc::with_center(Point::new(255, 480 * 59 / 100), 30, PI, -PI),
-                mouth_bottom: ConstArc::with_center(
-                    Point::new(240, 480 * 64 / 100),
-                    20,
-                    -FRAC_PI_6,
-                    0.,
-                ),
+                mouth: KerfurMouthType::NEUTRAL,
             },
             whisker: whisker::WhiskerState {
                 left: Line::new(
@@ -80,7 +103,9 @@ impl KerfurElements {
                     Point::new(480 * 100 / 100, 480 * 63 / 100),
                 ),
                 offset: Point::new(0, 24),
-                count: 2,
+                count: 2.,
+                angle: 0.,
+                spread: 0.,
             },
         }
     }
@@ -94,10 +119,113 @@ impl KerfurElements {
         self
     }
 
+    /// Use the given left eye, and its mirror image as the right eye.
+    #[inline]
+    #[must_use]
+    pub const fn with_symmetric_eyes(self, left: KerfurEyeType) -> Self {
+        self.with_eyes(left, left.mirrored())
+    }
+
+    /// Use the given eyelids in the set of facial elements.
+    ///
+    /// See [`KerfurEyelids`] for which eyes can be covered.
+    #[inline]
+    #[must_use]
+    pub const fn with_eyelids(mut self, left: KerfurEyelids, right: KerfurEyelids) -> Self {
+        self.eye.left_lids = left;
+        self.eye.right_lids = right;
+        self
+    }
+
+    /// Use the given left eyelids, and their mirror image as the right eyelids.
+    #[inline]
+    #[must_use]
+    pub const fn with_symmetric_eyelids(self, left: KerfurEyelids) -> Self {
+        self.with_eyelids(left, left.mirrored())
+    }
+
+    /// Use the given highlights on the pupils in the set of facial elements.
+    ///
+    /// Use the same highlights for both eyes to light them from the same
+    /// direction, or mirrored highlights to light them symmetrically.
+    #[inline]
+    #[must_use]
+    pub const fn with_highlights(
+        mut self,
+        left: KerfurHighlights,
+        right: KerfurHighlights,
+    ) -> Self {
+        self.eye.left_highlights = left;
+        self.eye.right_highlights = right;
+        self
+    }
+
+    /// Enable or disable clipping the pupils to the outside of the eyes.
+    ///
+    /// Pupils are clipped by default, so pupils moved or resized past the
+    /// edge of an eye are cut off instead of drawn outside of it.
+    #[inline]
+    #[must_use]
+    pub const fn with_pupil_clipping(mut self, clip: bool) -> Self {
+        self.eye.clip_pupils = clip;
+        self
+    }
+
+    /// Spin any [`KerfurEyeType::Swirl`] eyes by the given amount.
+    ///
+    /// The spin is in radians per second, and continuously rotates the swirls
+    /// instead of interpolating toward the target angle.
+    ///
+    /// A [`KerfurDisplay`](crate::KerfurDisplay) showing spinning eyes
+    /// is always animating.
+    #[inline]
+    #[must_use]
+    pub const fn with_eye_spin(mut self, spin: f32) -> Self {
+        self.eye.spin = spin;
+        self
+    }
+
     /// Use the given eyebrows in the set of facial elements.
     #[inline]
     #[must_use]
     pub const fn with_eyebrows(mut self, left: Line, right: Line) -> Self {
+        self.eyebrow.left = KerfurEyebrowType::Line(left);
+        self.eyebrow.right = KerfurEyebrowType::Line(right);
+        self
+    }
+
+    /// Use the given left eyebrow, and its mirror image as the right eyebrow.
+    #[inline]
+    #[must_use]
+    pub const fn with_symmetric_eyebrows(self, left: Line) -> Self {
+        self.with_eyebrows(left, mirror_line(left))
+    }
+
+    /// Use the given curved eyebrows in the set of facial elements.
+    #[inline]
+    #[must_use]
+    pub const fn with_curved_eyebrows(self, left: ConstArc, right: ConstArc) -> Self {
+        self.with_eyebrow_types(KerfurEyebrowType::Arc(left), KerfurEyebrowType::Arc(right))
+    }
+
+    /// Use the given curved left eyebrow,
+    /// and its mirror image as the right eyebrow.
+    #[inline]
+    #[must_use]
+    pub const fn with_symmetric_curved_eyebrows(self, left: ConstArc) -> Self {
+        self.with_curved_eyebrows(left, left.mirrored())
+    }
+
+    /// Use the given types of eyebrows in the set of facial elements.
+    ///
+    /// This allows one straight and one curved eyebrow.
+    #[inline]
+    #[must_use]
+    pub const fn with_eyebrow_types(
+        mut self,
+        left: KerfurEyebrowType,
+        right: KerfurEyebrowType,
+    ) -> Self {
         self.eyebrow.left = left;
         self.eyebrow.right = right;
         self
@@ -111,20 +239,39 @@ impl KerfurElements {
         self
     }
 
+    /// Use the given mouth in the set of facial elements.
+    #[inline]
+    #[must_use]
+    pub const fn with_mouth(mut self, mouth: KerfurMouthType) -> Self {
+        self.mouth.mouth = mouth;
+        self
+    }
+
     /// Use the given mouth sides in the set of facial elements.
+    ///
+    /// See [`KerfurMouthType::with_sides`] for mouths without sides.
     #[inline]
     #[must_use]
     pub const fn with_mouth_sides(mut self, mouth_left: ConstArc, mouth_right: ConstArc) -> Self {
-        self.mouth.mouth_left = mouth_left;
-        self.mouth.mouth_right = mouth_right;
+        self.mouth.mouth = self.mouth.mouth.with_sides(mouth_left, mouth_right);
         self
     }
 
+    /// Use the given left mouth side, and its mirror image as the right side.
+    #[inline]
+    #[must_use]
+    pub const fn with_symmetric_mouth_sides(self, mouth_left: ConstArc) -> Self {
+        self.with_mouth_sides(mouth_left, mouth_left.mirrored())
+    }
+
     /// Use the given mouth bottom in the set of facial elements.
+    ///
+    /// See [`KerfurMouthType::with_bottom`] for mouths other than
+    /// [`KerfurMouthType::Arcs`].
     #[inline]
     #[must_use]
     pub const fn with_mouth_bottom(mut self, mouth_bottom: ConstArc) -> Self {
-        self.mouth.mouth_bottom = mouth_bottom;
+        self.mouth.mouth = self.mouth.mouth.with_bottom(mouth_bottom);
         self
     }
 
@@ -137,12 +284,89 @@ impl KerfurElements {
         self
     }
 
+    /// Use the given left whisker, and its mirror image as the right whisker.
+    #[inline]
+    #[must_use]
+    pub const fn with_symmetric_whiskers(self, left: Line) -> Self {
+        self.with_whiskers(left, mirror_line(left))
+    }
+
     /// Use the given whisker settings in the set of facial elements.
     #[inline]
     #[must_use]
+    #[expect(clippy::cast_precision_loss, reason = "Whisker counts will never be that large")]
     pub const fn with_whisker_settings(mut self, offset: Point, count: u32) -> Self {
         self.whisker.offset = offset;
-        self.whisker.count = count;
+        self.whisker.count = count as f32;
+        self
+    }
+
+    /// Tilt the whiskers, in radians.
+    ///
+    /// Every whisker is tilted by `angle`, and each whisker after the first
+    /// is tilted by another `spread` so the whiskers fan out.
+    /// Positive angles tilt the outer ends of the whiskers down,
+    /// and the right whiskers tilt as the mirror image of the left whiskers.
+    #[inline]
+    #[must_use]
+    pub const fn with_whisker_angles(mut self, angle: f32, spread: f32) -> Self {
+        self.whisker.angle = angle;
+        self.whisker.spread = spread;
+        self
+    }
+
+    /// Point the pupils at a target on the canvas.
+    ///
+    /// Both eyes look in the same direction, as if the target was far away.
+    /// The pupils always stay inside the eyes,
+    /// and only [`KerfurEyeType::Ellipse`] eyes are affected.
+    #[must_use]
+    pub fn look_at(mut self, target: Point) -> Self {
+        self.eye.look_at(target, false);
+        self
+    }
+
+    /// Point the pupils at a nearby target on the canvas.
+    ///
+    /// Unlike [`KerfurElements::look_at`], each eye looks at the target
+    /// separately, so the eyes converge on targets close to the face.
+    #[must_use]
+    pub fn look_at_converging(mut self, target: Point) -> Self {
+        self.eye.look_at(target, true);
+        self
+    }
+
+    /// Mirror the whole face across its vertical center line.
+    ///
+    /// The left and right sides of the face swap places,
+    /// so a face winking with the left eye will wink with the right eye.
+    #[must_use]
+    pub const fn mirrored(mut self) -> Self {
+        let (eye, eyebrow, mouth, whisker) = (self.eye, self.eyebrow, self.mouth, self.whisker);
+        self.eye.left = eye.right.mirrored();
+        self.eye.right = eye.left.mirrored();
+        self.eye.left_lids = eye.right_lids.mirrored();
+        self.eye.right_lids = eye.left_lids.mirrored();
+        self.eye.left_highlights = eye.right_highlights.mirrored();
+        self.eye.right_highlights = eye.left_highlights.mirrored();
+        self.eyebrow.left = eyebrow.right.mirrored();
+        self.eyebrow.right = eyebrow.left.mirrored();
+        self.mouth.nose = mouth.nose.mirrored();
+        self.mouth.mouth = mouth.mouth.mirrored();
+        self.whisker.left = mirror_line(whisker.right);
+        self.whisker.right = mirror_line(whisker.left);
+        self
+    }
+
+    /// Map this set of elements from the canvas using the given layout.
+    ///
+    /// See [`KerfurLayout`] for more information.
+    #[must_use]
+    pub fn with_layout(mut self, layout: &KerfurLayout) -> Self {
+        self.eye.layout(layout);
+        self.eyebrow.layout(layout);
+        self.mouth.layout(layout);
+        self.whisker.layout(layout);
         self
     }
 
@@ -163,105 +387,260 @@ impl KerfurElements {
         Ok(())
     }
 
-    /// Interpolate this set of elements toward the target set.
-    pub(super) fn interpolate(&mut self, target: &Self, tick: f32) {
-        self.eye.interpolate(&target.eye, tick);
-        self.eyebrow.interpolate(&target.eyebrow, tick);
-        self.mouth.interpolate(&target.mouth, tick);
-        self.whisker.interpolate(&target.whisker, tick);
+    /// Blend between two expressions.
+    ///
+    /// A `factor` of `0.0` returns `a`, a `factor` of `1.0` returns `b`,
+    /// and values in between are part of the way from `a` to `b`.
+    /// The `factor` is clamped between `0.0` and `1.0`, and `NaN` is treated
+    /// as `0.0`.
+    ///
+    /// Unlike [`KerfurDisplay::set_expression`](crate::KerfurDisplay::set_expression),
+    /// this does not animate, and can be used to show an expression with a
+    /// varying intensity.
+    ///
+    /// ```rust
+    /// use kerfur_display::{KerfurElements, KerfurEmote};
+    ///
+    /// // A face 40% of the way from neutral to meowing
+    /// let elements = KerfurElements::blend(KerfurEmote::Neutral, KerfurEmote::Meow, 0.4);
+    /// ```
+    #[must_use]
+    pub fn blend<A: KerfurExpression, B: KerfurExpression>(a: A, b: B, factor: f32) -> Self {
+        let factor = if factor.is_nan() { 0. } else { factor.clamp(0., 1.) };
+        Self::interpolate(&a.into_elements(), &b.into_elements(), factor)
+    }
+
+    /// Get the amount any [`KerfurEyeType::Swirl`] eyes spin by.
+    #[inline]
+    #[must_use]
+    pub(super) const fn eye_spin(&self) -> f32 { self.eye.spin }
+
+    /// Rotate any [`KerfurEyeType::Swirl`] eyes by the given angle.
+    pub(super) fn rotate_swirls(&mut self, by: f32) { self.eye.rotate_swirls(by); }
+
+    /// Move every element by the given amount.
+    pub(crate) fn translate(&mut self, by: Point) {
+        self.eye.translate(by);
+        self.eyebrow.translate(by);
+        self.mouth.translate(by);
+        self.whisker.translate(by);
+    }
+
+    /// Move the pupils of any [`KerfurEyeType::Ellipse`] eyes.
+    pub(crate) fn look(&mut self, by: Point) { self.eye.look(by); }
+
+    /// Close any [`KerfurEyeType::Ellipse`] eyes by the given amount.
+    ///
+    /// An `amount` of `0.0` leaves the eyes open, and `1.0` fully closes them.
+    pub(crate) fn close_eyes(&mut self, amount: f32) { self.eye.close(amount); }
+
+    /// Open the mouth by the given amount.
+    ///
+    /// An `amount` of `0.0` leaves the mouth as it is, and `1.0` fully opens
+    /// it.
+    pub(crate) fn open_mouth(&mut self, amount: f32) { self.mouth.open(amount); }
+
+    /// Tilt the whiskers for part of a twitch.
+    ///
+    /// A `progress` of `0.0` is the start of the twitch and `1.0` is the end.
+    pub(crate) fn twitch_whiskers(&mut self, progress: f32) { self.whisker.twitch(progress); }
+
+    /// Get the furthest distance any element moves between two sets of
+    /// elements, in pixels.
+    #[must_use]
+    pub(super) fn distance(a: &Self, b: &Self) -> f32 {
+        Self::distances(a, b).into_iter().fold(0., f32::max)
+    }
+
+    /// Get the distance each part of the face moves between two sets of
+    /// elements, in pixels.
+    ///
+    /// The parts are the eyes, eyelids, highlights, eyebrows, nose, mouth
+    /// and whiskers, each of which can be interpolated separately.
+    #[must_use]
+    pub(super) fn distances(a: &Self, b: &Self) -> [f32; PARTS] {
+        let [e0, e1, e2, e3, e4, e5] = eye::EyeState::distances(&a.eye, &b.eye);
+        let [b0, b1] = eyebrow::EyebrowState::distances(&a.eyebrow, &b.eyebrow);
+        let [m0, m1] = mouth::MouthState::distances(&a.mouth, &b.mouth);
+        let [w0, w1, w2] = whisker::WhiskerState::distances(&a.whisker, &b.whisker);
+        [e0, e1, e2, e3, e4, e5, b0, b1, m0, m1, w0, w1, w2]
+    }
+
+    /// Interpolate between two sets of elements.
+    ///
+    /// Every element moves the same fraction of the way toward its target,
+    /// so all elements arrive at the same time.
+    ///
+    /// A `progress` of `0.0` returns `a` and a `progress` of `1.0` returns `b`.
+    #[must_use]
+    pub(super) fn interpolate(a: &Self, b: &Self, progress: f32) -> Self {
+        Self::interpolate_parts(a, b, [progress; PARTS])
+    }
+
+    /// Interpolate each part of the face by its own progress,
+    /// in the same order as [`KerfurElements::distances`].
+    #[must_use]
+    pub(super) fn interpolate_parts(a: &Self, b: &Self, progress: [f32; PARTS]) -> Self {
+        let [e0, e1, e2, e3, e4, e5, b0, b1, m0, m1, w0, w1, w2] = progress;
+        Self {
+            eye: eye::EyeState::interpolate(&a.eye, &b.eye, [e0, e1, e2, e3, e4, e5]),
+            eyebrow: eyebrow::EyebrowState::interpolate(&a.eyebrow, &b.eyebrow, [b0, b1]),
+            mouth: mouth::MouthState::interpolate(&a.mouth, &b.mouth, [m0, m1]),
+            whisker: whisker::WhiskerState::interpolate(&a.whisker, &b.whisker, [w0, w1, w2]),
+        }
     }
 }
 
 // -------------------------------------------------------------------------------------------------
 
-// TODO: Fix negative?
-fn interp(a_x: f32, a_y: f32, b_x: f32, b_y: f32, t: f32) -> (f32, f32) {
-    let (diff_x, diff_y) = (b_x - a_x, b_y - a_y);
-    let dot = (diff_x * diff_x) + (diff_y * diff_y);
-
-    #[cfg(feature = "libm")]
-    let len = libm::sqrtf(dot);
-    #[cfg(not(feature = "libm"))]
-    let len = dot.sqrt();
+fn layout_line(line: &mut Line, layout: &KerfurLayout) {
+    line.start = layout.point(line.start);
+    line.end = layout.point(line.end);
+}
 
-    if len <= t || len <= 1e-4 {
-        (b_x, b_y)
+fn layout_circle(top_left: &mut Point, diameter: &mut u32, layout: &KerfurLayout) {
+    let circle = layout.rectangle(Rectangle::new(*top_left, Size::new_equal(*diameter)));
+    if layout.transform().is_uniform() {
+        *top_left = circle.top_left;
+        *diameter = circle.size.width;
     } else {
-        (a_x + diff_x / len * t, a_y + diff_y / len * t)
+        // Keep circles round when the face is squashed
+        *diameter = layout.length(*diameter);
+        *top_left = Rectangle::with_center(circle.center(), Size::new_equal(*diameter)).top_left;
     }
 }
 
+fn layout_arc(arc: &mut ConstArc, layout: &KerfurLayout) {
+    layout_circle(&mut arc.top_left, &mut arc.diameter, layout);
+    arc.angle_start = layout.angle(arc.angle_start);
+}
+
+fn layout_sector(sector: &mut ConstSector, layout: &KerfurLayout) {
+    layout_circle(&mut sector.top_left, &mut sector.diameter, layout);
+    sector.angle_start = layout.angle(sector.angle_start);
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Exact at both `t = 0.0` and `t = 1.0`, so transitions always converge.
+fn lerp(a: f32, b: f32, t: f32) -> f32 { a * (1. - t) + b * t }
+
 #[expect(clippy::cast_precision_loss, reason = "Positions will never be that large")]
 #[expect(clippy::cast_possible_truncation, reason = "Positions will never be that large")]
-fn interp_point(a: &mut Point, b: Point, t: f32) {
-    let ceil_or_floor = matches!(Point::cmp(a, &b), Ordering::Less);
-    let (x, y) = interp(a.x as f32, a.y as f32, b.x as f32, b.y as f32, t);
-    if ceil_or_floor {
-        #[cfg(feature = "libm")]
-        {
-            (a.x, a.y) = (libm::ceilf(x) as i32, libm::ceilf(y) as i32);
-        }
-        #[cfg(not(feature = "libm"))]
-        {
-            (a.x, a.y) = (x.ceil() as i32, y.ceil() as i32);
-        }
-    } else {
-        #[cfg(feature = "libm")]
-        {
-            (a.x, a.y) = (libm::floorf(x) as i32, libm::floorf(y) as i32);
-        }
-        #[cfg(not(feature = "libm"))]
-        {
-            (a.x, a.y) = (x.floor() as i32, y.floor() as i32);
-        }
-    }
+fn lerp_i32(a: i32, b: i32, t: f32) -> i32 { roundf(lerp(a as f32, b as f32, t)) as i32 }
+
+#[expect(clippy::cast_precision_loss, reason = "Sizes will never be that large")]
+#[expect(clippy::cast_possible_truncation, reason = "Sizes will never be that large")]
+#[expect(clippy::cast_sign_loss, reason = "Sizes are clamped to zero")]
+fn lerp_u32(a: u32, b: u32, t: f32) -> u32 { roundf(lerp(a as f32, b as f32, t)).max(0.) as u32 }
+
+// Rotate along the shortest path between two angles.
+//
+// Each half is measured from its nearest end, so both ends are exact.
+fn lerp_angle(a: f32, b: f32, t: f32) -> f32 {
+    let delta = angle_between(a, b);
+    if t < 0.5 { a + delta * t } else { b - delta * (1. - t) }
 }
 
-#[expect(clippy::cast_sign_loss, reason = "Size will never be negative")]
-#[expect(clippy::cast_precision_loss, reason = "Size will never be that large")]
-#[expect(clippy::cast_possible_truncation, reason = "Size will never be that large")]
-fn interp_size(a: &mut Size, b: Size, t: f32) {
-    let ceil_or_floor = matches!(Size::cmp(a, &b), Ordering::Less);
-    let (w, h) = interp(a.width as f32, a.height as f32, b.width as f32, b.height as f32, t);
-    if ceil_or_floor {
-        #[cfg(feature = "libm")]
-        {
-            (a.width, a.height) = (libm::ceilf(w) as u32, libm::ceilf(h) as u32);
-        }
-        #[cfg(not(feature = "libm"))]
-        {
-            (a.width, a.height) = (w.ceil() as u32, h.ceil() as u32);
-        }
-    } else {
-        #[cfg(feature = "libm")]
-        {
-            (a.width, a.height) = (libm::floorf(w) as u32, libm::floorf(h) as u32);
-        }
-        #[cfg(not(feature = "libm"))]
-        {
-            (a.width, a.height) = (w.floor() as u32, h.floor() as u32);
-        }
-    }
+fn lerp_point(a: Point, b: Point, t: f32) -> Point {
+    Point::new(lerp_i32(a.x, b.x, t), lerp_i32(a.y, b.y, t))
 }
 
-fn interp_angle(a: &mut f32, b: f32, t: f32) {
-    let (a_rad, b_rad) = (a.to_radians(), b.to_radians());
-    let diff = b_rad - a_rad;
-    let dot = diff * diff;
+fn lerp_size(a: Size, b: Size, t: f32) -> Size {
+    Size::new(lerp_u32(a.width, b.width, t), lerp_u32(a.height, b.height, t))
+}
 
-    #[cfg(feature = "libm")]
-    let len = libm::sqrtf(dot);
-    #[cfg(not(feature = "libm"))]
-    let len = dot.sqrt();
+fn lerp_rectangle(a: &Rectangle, b: &Rectangle, t: f32) -> Rectangle {
+    Rectangle::new(lerp_point(a.top_left, b.top_left, t), lerp_size(a.size, b.size, t))
+}
 
-    if len <= t || len <= 1e-4 {
-        *a = b;
-    } else {
-        *a = a_rad + diff / len * t;
-    }
+fn lerp_line(a: &Line, b: &Line, t: f32) -> Line {
+    Line::new(lerp_point(a.start, b.start, t), lerp_point(a.end, b.end, t))
+}
+
+fn lerp_ellipse(a: &Ellipse, b: &Ellipse, t: f32) -> Ellipse {
+    Ellipse::new(lerp_point(a.top_left, b.top_left, t), lerp_size(a.size, b.size, t))
+}
+
+fn lerp_arc(a: &ConstArc, b: &ConstArc, t: f32) -> ConstArc {
+    ConstArc::new(
+        lerp_point(a.top_left, b.top_left, t),
+        lerp_u32(a.diameter, b.diameter, t),
+        lerp_angle(a.angle_start, b.angle_start, t),
+        lerp(a.angle_sweep, b.angle_sweep, t),
+    )
+}
+
+fn lerp_sector(a: &ConstSector, b: &ConstSector, t: f32) -> ConstSector {
+    ConstSector::new(
+        lerp_point(a.top_left, b.top_left, t),
+        lerp_u32(a.diameter, b.diameter, t),
+        lerp_angle(a.angle_start, b.angle_start, t),
+        lerp(a.angle_sweep, b.angle_sweep, t),
+    )
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Add two points, saturating instead of overflowing.
+const fn saturating_add(a: Point, b: Point) -> Point {
+    Point::new(a.x.saturating_add(b.x), a.y.saturating_add(b.y))
+}
+
+// Subtract two points, saturating instead of overflowing.
+const fn saturating_sub(a: Point, b: Point) -> Point {
+    Point::new(a.x.saturating_sub(b.x), a.y.saturating_sub(b.y))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[expect(clippy::cast_precision_loss, reason = "Positions will never be that large")]
+fn distance_point(a: Point, b: Point) -> f32 {
+    let (x, y) = ((b.x - a.x) as f32, (b.y - a.y) as f32);
+    sqrtf(x * x + y * y)
+}
+
+#[expect(clippy::cast_precision_loss, reason = "Sizes will never be that large")]
+fn distance_u32(a: u32, b: u32) -> f32 { a.abs_diff(b) as f32 }
+
+// The distance the edge of a circle moves when rotated between two angles.
+#[expect(clippy::cast_precision_loss, reason = "Diameters will never be that large")]
+fn distance_angle(a: f32, b: f32, diameter: u32) -> f32 {
+    angle_between(a, b).abs() * diameter as f32 / 2.
+}
+
+// The distance the end of an arc moves when its sweep changes.
+#[expect(clippy::cast_precision_loss, reason = "Diameters will never be that large")]
+fn distance_sweep(a: f32, b: f32, diameter: u32) -> f32 { (b - a).abs() * diameter as f32 / 2. }
+
+fn distance_size(a: Size, b: Size) -> f32 {
+    distance_u32(a.width, b.width).max(distance_u32(a.height, b.height))
+}
+
+fn distance_line(a: &Line, b: &Line) -> f32 {
+    distance_point(a.start, b.start).max(distance_point(a.end, b.end))
+}
+
+fn distance_rectangle(a: &Rectangle, b: &Rectangle) -> f32 {
+    distance_point(a.top_left, b.top_left).max(distance_size(a.size, b.size))
+}
+
+fn distance_ellipse(a: &Ellipse, b: &Ellipse) -> f32 {
+    distance_point(a.top_left, b.top_left).max(distance_size(a.size, b.size))
+}
+
+fn distance_arc(a: &ConstArc, b: &ConstArc) -> f32 {
+    let diameter = a.diameter.max(b.diameter);
+    distance_point(a.top_left, b.top_left)
+        .max(distance_u32(a.diameter, b.diameter))
+        .max(distance_angle(a.angle_start, b.angle_start, diameter))
+        .max(distance_sweep(a.angle_sweep, b.angle_sweep, diameter))
 }
 
-fn interp_line(a: &mut Line, b: &Line, t: f32) {
-    interp_point(&mut a.start, b.start, t);
-    interp_point(&mut a.end, b.end, t);
+fn distance_sector(a: &ConstSector, b: &ConstSector) -> f32 {
+    let diameter = a.diameter.max(b.diameter);
+    distance_point(a.top_left, b.top_left)
+        .max(distance_u32(a.diameter, b.diameter))
+        .max(distance_angle(a.angle_start, b.angle_start, diameter))
+        .max(distance_sweep(a.angle_sweep, b.angle_sweep, diameter))
 }
@@ -1,58 +1,514 @@
-use embedded_graphics::{prelude::*, primitives::StyledDrawable};
+use core::f32::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_6, PI};
+
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Ellipse, Line, PrimitiveStyle, Rectangle, StyledDrawable, Triangle},
+};
 
 use crate::{
-    KerfurStyle,
-    element::{interp_angle, interp_point, interp_size},
-    primitive::{ConstArc, ConstSector},
+    KerfurLayout, KerfurStyle,
+    element::{
+        distance_arc, distance_ellipse, distance_line, distance_rectangle, distance_sector,
+        distance_u32, layout_arc, layout_line, layout_sector, lerp_arc, lerp_ellipse, lerp_line,
+        lerp_sector, lerp_u32, saturating_add,
+    },
+    math::{cosf, roundf, sinf},
+    primitive::{ConstArc, ConstSector, mirror_ellipse, mirror_line},
 };
 
 #[derive(Clone, Copy, PartialEq)]
 pub(super) struct MouthState {
     pub(super) nose: ConstSector,
-    pub(super) mouth_left: ConstArc,
-    pub(super) mouth_right: ConstArc,
-    pub(super) mouth_bottom: ConstArc,
+    pub(super) mouth: KerfurMouthType,
+}
+
+/// The type of mouth to display
+#[derive(Clone, Copy, PartialEq)]
+pub enum KerfurMouthType {
+    /// A left side, right side, and bottom arc
+    Arcs(ConstArc, ConstArc, ConstArc),
+    /// A left and right side, with a fang below each side of the given length
+    Fangs(ConstArc, ConstArc, u32),
+    /// A left and right side, with a tongue poking out below them
+    Tongue(ConstArc, ConstArc, ConstSector),
+    /// A single arc, such as a smile or a frown
+    Curve(ConstArc),
+    /// An open ellipse
+    Open(Ellipse),
+    /// A flat line
+    Line(Line),
+}
+
+impl KerfurMouthType {
+    /// The default, neutral mouth.
+    pub const NEUTRAL: KerfurMouthType = KerfurMouthType::Arcs(
+        ConstArc::with_center(Point::new(225, 480 * 59 / 100), 30, 0., PI),
+        ConstArc::with_center(Point::new(255, 480 * 59 / 100), 30, PI, -PI),
+        ConstArc::with_center(Point::new(240, 480 * 64 / 100), 20, -FRAC_PI_6, 0.),
+    );
+
+    /// Returns the mouth with the given left and right sides.
+    ///
+    /// Mouths without sides are replaced with a [`KerfurMouthType::Arcs`]
+    /// mouth, using the bottom of the [`KerfurMouthType::NEUTRAL`] mouth.
+    #[must_use]
+    pub const fn with_sides(self, left: ConstArc, right: ConstArc) -> Self {
+        match self {
+            KerfurMouthType::Arcs(_, _, bottom) => KerfurMouthType::Arcs(left, right, bottom),
+            KerfurMouthType::Fangs(_, _, length) => KerfurMouthType::Fangs(left, right, length),
+            KerfurMouthType::Tongue(_, _, tongue) => KerfurMouthType::Tongue(left, right, tongue),
+            KerfurMouthType::Curve(_) | KerfurMouthType::Open(_) | KerfurMouthType::Line(_) => {
+                KerfurMouthType::NEUTRAL.with_sides(left, right)
+            }
+        }
+    }
+
+    /// Returns the mouth with the given bottom arc.
+    ///
+    /// Mouths other than [`KerfurMouthType::Arcs`] are replaced with one,
+    /// keeping their sides if they have any.
+    #[must_use]
+    pub const fn with_bottom(self, bottom: ConstArc) -> Self {
+        match self {
+            KerfurMouthType::Arcs(left, right, _)
+            | KerfurMouthType::Fangs(left, right, _)
+            | KerfurMouthType::Tongue(left, right, _) => KerfurMouthType::Arcs(left, right, bottom),
+            KerfurMouthType::Curve(_) | KerfurMouthType::Open(_) | KerfurMouthType::Line(_) => {
+                KerfurMouthType::NEUTRAL.with_bottom(bottom)
+            }
+        }
+    }
+
+    /// Returns the mouth mirrored across the face's vertical center line.
+    ///
+    /// See [`primitive`](crate::primitive) for how each shape is mirrored.
+    #[must_use]
+    pub const fn mirrored(self) -> Self {
+        match self {
+            KerfurMouthType::Arcs(left, right, bottom) => {
+                KerfurMouthType::Arcs(right.mirrored(), left.mirrored(), bottom.mirrored())
+            }
+            KerfurMouthType::Fangs(left, right, length) => {
+                KerfurMouthType::Fangs(right.mirrored(), left.mirrored(), length)
+            }
+            KerfurMouthType::Tongue(left, right, tongue) => {
+                KerfurMouthType::Tongue(right.mirrored(), left.mirrored(), tongue.mirrored())
+            }
+            KerfurMouthType::Curve(curve) => KerfurMouthType::Curve(curve.mirrored()),
+            KerfurMouthType::Open(open) => KerfurMouthType::Open(mirror_ellipse(open)),
+            KerfurMouthType::Line(line) => KerfurMouthType::Line(mirror_line(line)),
+        }
+    }
+
+    /// Get the left and right sides of the mouth, if it has any.
+    const fn sides(&self) -> Option<(ConstArc, ConstArc)> {
+        match *self {
+            KerfurMouthType::Arcs(left, right, _)
+            | KerfurMouthType::Fangs(left, right, _)
+            | KerfurMouthType::Tongue(left, right, _) => Some((left, right)),
+            KerfurMouthType::Curve(_) | KerfurMouthType::Open(_) | KerfurMouthType::Line(_) => None,
+        }
+    }
+
+    /// Returns the same type of mouth, with the part below the sides
+    /// shrunk away to nothing.
+    fn without_bottom(&self) -> Self {
+        match *self {
+            KerfurMouthType::Arcs(left, right, bottom) => KerfurMouthType::Arcs(
+                left,
+                right,
+                ConstArc::new(
+                    bottom.top_left,
+                    bottom.diameter,
+                    bottom.angle_start + bottom.angle_sweep / 2.,
+                    0.,
+                ),
+            ),
+            KerfurMouthType::Fangs(left, right, _) => KerfurMouthType::Fangs(left, right, 0),
+            KerfurMouthType::Tongue(left, right, tongue) => KerfurMouthType::Tongue(
+                left,
+                right,
+                ConstSector::with_center(
+                    tongue.center(),
+                    0,
+                    tongue.angle_start,
+                    tongue.angle_sweep,
+                ),
+            ),
+            mouth => mouth,
+        }
+    }
+
+    /// Returns the mouth opened as wide as it goes.
+    ///
+    /// The sides move apart while the bottom drops below them,
+    /// and fangs grow longer as the mouth opens.
+    fn opened(&self) -> Self {
+        let spread = |left: ConstArc, right: ConstArc| {
+            let (mut left, mut right) = (left, right);
+            left.top_left.x -= OPEN_SPREAD;
+            right.top_left.x += OPEN_SPREAD;
+            (left, right)
+        };
+        match *self {
+            KerfurMouthType::Arcs(left, right, _) => {
+                let (left, right) = spread(left, right);
+
+                // Hang the bottom between the sides, reaching just below them
+                let (left_center, right_center) = (left.center(), right.center());
+                let diameter = (right_center.x - left_center.x).unsigned_abs()
+                    + left.diameter.max(right.diameter);
+                let center = Point::new(
+                    i32::midpoint(left_center.x, right_center.x),
+                    i32::midpoint(left_center.y, right_center.y) + (diameter / 2).cast_signed(),
+                );
+                let bottom = ConstArc::with_center(center, diameter, -FRAC_PI_6, PI + FRAC_PI_3);
+                KerfurMouthType::Arcs(left, right, bottom)
+            }
+            KerfurMouthType::Fangs(left, right, length) => {
+                let (left, right) = spread(left, right);
+                KerfurMouthType::Fangs(left, right, length + OPEN_DEPTH.cast_unsigned() / 2)
+            }
+            KerfurMouthType::Tongue(left, right, tongue) => {
+                let (left, right) = spread(left, right);
+                let tongue = ConstSector::with_center(
+                    tongue.center() + Point::new(0, OPEN_DEPTH / 2),
+                    tongue.diameter + OPEN_DEPTH.cast_unsigned() / 2,
+                    tongue.angle_start,
+                    tongue.angle_sweep,
+                );
+                KerfurMouthType::Tongue(left, right, tongue)
+            }
+            KerfurMouthType::Curve(curve) => {
+                // Curl the ends around until the curve is almost a circle
+                let middle = curve.angle_start + curve.angle_sweep / 2.;
+                let sweep = curve.angle_sweep.abs().max(OPEN_SWEEP) * curve.angle_sweep.signum();
+                KerfurMouthType::Curve(ConstArc::new(
+                    curve.top_left,
+                    curve.diameter,
+                    middle - sweep / 2.,
+                    sweep,
+                ))
+            }
+            KerfurMouthType::Open(open) => KerfurMouthType::Open(Ellipse::new(
+                open.top_left,
+                open.size + Size::new(0, OPEN_DEPTH.cast_unsigned()),
+            )),
+            KerfurMouthType::Line(line) => {
+                let open = line_as_open(&line);
+                let size =
+                    Size::new(open.size.width, open.size.height + OPEN_DEPTH.cast_unsigned());
+                KerfurMouthType::Open(Ellipse::new(open.top_left, size))
+            }
+        }
+    }
+
+    /// Returns the same type of mouth, shrunk down to a single point.
+    fn collapsed(&self, center: Point) -> Self {
+        let arc = |arc: ConstArc| {
+            ConstArc::with_center(center, 0, arc.angle_start + arc.angle_sweep / 2., 0.)
+        };
+        match *self {
+            KerfurMouthType::Arcs(left, right, bottom) => {
+                KerfurMouthType::Arcs(arc(left), arc(right), arc(bottom))
+            }
+            KerfurMouthType::Fangs(left, right, _) => {
+                KerfurMouthType::Fangs(arc(left), arc(right), 0)
+            }
+            KerfurMouthType::Tongue(left, right, tongue) => KerfurMouthType::Tongue(
+                arc(left),
+                arc(right),
+                ConstSector::with_center(center, 0, tongue.angle_start, tongue.angle_sweep),
+            ),
+            KerfurMouthType::Curve(curve) => KerfurMouthType::Curve(arc(curve)),
+            KerfurMouthType::Open(_) => {
+                KerfurMouthType::Open(Ellipse::with_center(center, Size::zero()))
+            }
+            KerfurMouthType::Line(_) => KerfurMouthType::Line(Line::new(center, center)),
+        }
+    }
+}
+
+impl Dimensions for KerfurMouthType {
+    fn bounding_box(&self) -> Rectangle {
+        let arc = |arc: &ConstArc| Rectangle::new(arc.top_left, Size::new_equal(arc.diameter));
+        match self {
+            KerfurMouthType::Arcs(left, right, _)
+            | KerfurMouthType::Fangs(left, right, _)
+            | KerfurMouthType::Tongue(left, right, _) => {
+                let (left, right) = (arc(left), arc(right));
+                let top_left = left.top_left.component_min(right.top_left);
+                let bottom_right =
+                    (left.top_left + left.size).component_max(right.top_left + right.size);
+                Rectangle::with_corners(top_left, bottom_right - Point::new(1, 1))
+            }
+            KerfurMouthType::Curve(curve) => arc(curve),
+            KerfurMouthType::Open(open) => open.bounding_box(),
+            KerfurMouthType::Line(line) => line.bounding_box(),
+        }
+    }
+}
+
+impl Transform for KerfurMouthType {
+    fn translate(&self, by: Point) -> Self {
+        let mut mouth = *self;
+        mouth.translate_mut(by);
+        mouth
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        match self {
+            KerfurMouthType::Arcs(left, right, bottom) => {
+                left.top_left = saturating_add(left.top_left, by);
+                right.top_left = saturating_add(right.top_left, by);
+                bottom.top_left = saturating_add(bottom.top_left, by);
+            }
+            KerfurMouthType::Fangs(left, right, _) => {
+                left.top_left = saturating_add(left.top_left, by);
+                right.top_left = saturating_add(right.top_left, by);
+            }
+            KerfurMouthType::Tongue(left, right, tongue) => {
+                left.top_left = saturating_add(left.top_left, by);
+                right.top_left = saturating_add(right.top_left, by);
+                tongue.top_left = saturating_add(tongue.top_left, by);
+            }
+            KerfurMouthType::Curve(curve) => curve.top_left = saturating_add(curve.top_left, by),
+            KerfurMouthType::Open(open) => open.top_left = saturating_add(open.top_left, by),
+            KerfurMouthType::Line(line) => {
+                line.start = saturating_add(line.start, by);
+                line.end = saturating_add(line.end, by);
+            }
+        }
+        self
+    }
 }
 
-#[expect(warnings, reason = "WIP")]
 impl MouthState {
+    /// The number of parts of the nose and mouth that move separately.
+    pub(super) const PARTS: usize = 2;
+
     pub(super) fn draw<D: DrawTargetExt>(
-        &mut self,
+        &self,
         display: &mut D,
         style: &KerfurStyle<D::Color>,
     ) -> Result<(), D::Error> {
-        self.mouth_bottom.into_arc().draw_styled(&style.mouth_bottom, display)?;
-        self.mouth_left.into_arc().draw_styled(&style.mouth, display)?;
-        self.mouth_right.into_arc().draw_styled(&style.mouth, display)?;
+        match &self.mouth {
+            KerfurMouthType::Arcs(left, right, bottom) => {
+                bottom.into_arc().draw_styled(&style.mouth_bottom, display)?;
+                left.into_arc().draw_styled(&style.mouth, display)?;
+                right.into_arc().draw_styled(&style.mouth, display)?;
+            }
+            KerfurMouthType::Fangs(left, right, length) => {
+                for side in [left, right] {
+                    draw_fang(side, *length, display, &style.mouth)?;
+                    side.into_arc().draw_styled(&style.mouth, display)?;
+                }
+            }
+            KerfurMouthType::Tongue(left, right, tongue) => {
+                tongue.into_sector().draw_styled(&style.mouth_bottom, display)?;
+                left.into_arc().draw_styled(&style.mouth, display)?;
+                right.into_arc().draw_styled(&style.mouth, display)?;
+            }
+            KerfurMouthType::Curve(curve) => curve.into_arc().draw_styled(&style.mouth, display)?,
+            KerfurMouthType::Open(open) => open.draw_styled(&style.mouth_bottom, display)?,
+            KerfurMouthType::Line(line) => line.draw_styled(&style.mouth, display)?,
+        }
         self.nose.into_sector().draw_styled(&style.nose, display)
     }
 
-    pub(super) fn interpolate(&mut self, other: &Self, tick: f32) {
-        Self::interpolate_sector(&mut self.nose, &other.nose, tick);
-        Self::interpolate_arc(&mut self.mouth_left, &other.mouth_left, tick);
-        Self::interpolate_arc(&mut self.mouth_right, &other.mouth_right, tick);
-        Self::interpolate_arc(&mut self.mouth_bottom, &other.mouth_bottom, tick);
+    /// Open the mouth by the given amount.
+    ///
+    /// An `amount` of `0.0` leaves the mouth as it is, and `1.0` fully opens
+    /// it.
+    pub(super) fn open(&mut self, amount: f32) {
+        self.mouth = Self::interpolate_mouth(&self.mouth, &self.mouth.opened(), amount);
+    }
+
+    pub(super) fn translate(&mut self, by: Point) {
+        self.nose.top_left = saturating_add(self.nose.top_left, by);
+        self.mouth.translate_mut(by);
+    }
+
+    /// Get the distance the nose and the mouth move.
+    pub(super) fn distances(a: &Self, b: &Self) -> [f32; Self::PARTS] {
+        [distance_sector(&a.nose, &b.nose), Self::distance_mouth(&a.mouth, &b.mouth)]
+    }
+
+    fn distance_mouth(a: &KerfurMouthType, b: &KerfurMouthType) -> f32 {
+        match (a, b) {
+            (KerfurMouthType::Arcs(a1, a2, a3), KerfurMouthType::Arcs(b1, b2, b3)) => {
+                distance_arc(a1, b1).max(distance_arc(a2, b2)).max(distance_arc(a3, b3))
+            }
+            (KerfurMouthType::Fangs(a1, a2, a3), KerfurMouthType::Fangs(b1, b2, b3)) => {
+                distance_arc(a1, b1).max(distance_arc(a2, b2)).max(distance_u32(*a3, *b3))
+            }
+            (KerfurMouthType::Tongue(a1, a2, a3), KerfurMouthType::Tongue(b1, b2, b3)) => {
+                distance_arc(a1, b1).max(distance_arc(a2, b2)).max(distance_sector(a3, b3))
+            }
+            (KerfurMouthType::Curve(a), KerfurMouthType::Curve(b)) => distance_arc(a, b),
+            (KerfurMouthType::Open(a), KerfurMouthType::Open(b)) => distance_ellipse(a, b),
+            (KerfurMouthType::Line(a), KerfurMouthType::Line(b)) => distance_line(a, b),
+            // Different mouth shapes move between their bounding boxes
+            (a, b) => distance_rectangle(&a.bounding_box(), &b.bounding_box()),
+        }
     }
 
-    fn interpolate_sector(a: &mut ConstSector, b: &ConstSector, tick: f32) {
-        interp_point(&mut a.top_left, b.top_left, tick);
-        interp_angle(&mut a.angle_start, b.angle_start, tick);
-        interp_angle(&mut a.angle_sweep, b.angle_sweep, tick);
-        interp_diameter(&mut a.diameter, b.diameter, tick);
+    /// Interpolate the nose and the mouth by their own progress.
+    pub(super) fn interpolate(a: &Self, b: &Self, [nose, mouth]: [f32; Self::PARTS]) -> Self {
+        Self {
+            nose: lerp_sector(&a.nose, &b.nose, nose),
+            mouth: Self::interpolate_mouth(&a.mouth, &b.mouth, mouth),
+        }
     }
 
-    fn interpolate_arc(a: &mut ConstArc, b: &ConstArc, tick: f32) {
-        interp_point(&mut a.top_left, b.top_left, tick);
-        interp_angle(&mut a.angle_start, b.angle_start, tick);
-        interp_angle(&mut a.angle_sweep, b.angle_sweep, tick);
-        interp_diameter(&mut a.diameter, b.diameter, tick);
+    fn interpolate_mouth(a: &KerfurMouthType, b: &KerfurMouthType, t: f32) -> KerfurMouthType {
+        match (a, b) {
+            (KerfurMouthType::Arcs(a1, a2, a3), KerfurMouthType::Arcs(b1, b2, b3)) => {
+                KerfurMouthType::Arcs(lerp_arc(a1, b1, t), lerp_arc(a2, b2, t), lerp_arc(a3, b3, t))
+            }
+            (KerfurMouthType::Fangs(a1, a2, a3), KerfurMouthType::Fangs(b1, b2, b3)) => {
+                KerfurMouthType::Fangs(
+                    lerp_arc(a1, b1, t),
+                    lerp_arc(a2, b2, t),
+                    lerp_u32(*a3, *b3, t),
+                )
+            }
+            (KerfurMouthType::Tongue(a1, a2, a3), KerfurMouthType::Tongue(b1, b2, b3)) => {
+                KerfurMouthType::Tongue(
+                    lerp_arc(a1, b1, t),
+                    lerp_arc(a2, b2, t),
+                    lerp_sector(a3, b3, t),
+                )
+            }
+            (KerfurMouthType::Curve(a), KerfurMouthType::Curve(b)) => {
+                KerfurMouthType::Curve(lerp_arc(a, b, t))
+            }
+            (KerfurMouthType::Open(a), KerfurMouthType::Open(b)) => {
+                KerfurMouthType::Open(lerp_ellipse(a, b, t))
+            }
+            (KerfurMouthType::Line(a), KerfurMouthType::Line(b)) => {
+                KerfurMouthType::Line(lerp_line(a, b, t))
+            }
+            // Squash open mouths into a flat ellipse, then swap to the line
+            (KerfurMouthType::Open(_), KerfurMouthType::Line(line)) => {
+                if t >= 1. {
+                    *b
+                } else {
+                    Self::interpolate_mouth(a, &KerfurMouthType::Open(line_as_open(line)), t)
+                }
+            }
+            (KerfurMouthType::Line(line), KerfurMouthType::Open(_)) => {
+                if t <= 0. {
+                    *a
+                } else {
+                    Self::interpolate_mouth(&KerfurMouthType::Open(line_as_open(line)), b, t)
+                }
+            }
+            (a, b) => {
+                // Keep moving the sides, while shrinking the current bottom away
+                // and then growing the new bottom in its place
+                if let (Some((a1, a2)), Some((b1, b2))) = (a.sides(), b.sides()) {
+                    let mouth = if t < 0.5 {
+                        Self::interpolate_mouth(a, &a.without_bottom(), t * 2.)
+                    } else {
+                        Self::interpolate_mouth(&b.without_bottom(), b, t * 2. - 1.)
+                    };
+                    return mouth.with_sides(lerp_arc(&a1, &b1, t), lerp_arc(&a2, &b2, t));
+                }
+
+                // Shrink the current mouth away, then grow the new mouth in its place
+                let center = Point::new(
+                    i32::midpoint(a.bounding_box().center().x, b.bounding_box().center().x),
+                    i32::midpoint(a.bounding_box().center().y, b.bounding_box().center().y),
+                );
+                if t < 0.5 {
+                    Self::interpolate_mouth(a, &a.collapsed(center), t * 2.)
+                } else {
+                    Self::interpolate_mouth(&b.collapsed(center), b, t * 2. - 1.)
+                }
+            }
+        }
+    }
+
+    pub(super) fn layout(&mut self, layout: &KerfurLayout) {
+        layout_sector(&mut self.nose, layout);
+        let arc = |arc: &mut ConstArc| layout_arc(arc, layout);
+        match &mut self.mouth {
+            KerfurMouthType::Arcs(left, right, bottom) => {
+                arc(left);
+                arc(right);
+                arc(bottom);
+            }
+            KerfurMouthType::Fangs(left, right, length) => {
+                arc(left);
+                arc(right);
+                *length = layout.length(*length);
+            }
+            KerfurMouthType::Tongue(left, right, tongue) => {
+                arc(left);
+                arc(right);
+                layout_sector(tongue, layout);
+            }
+            KerfurMouthType::Curve(curve) => arc(curve),
+            KerfurMouthType::Open(open) => {
+                let rect = layout.rectangle(open.bounding_box());
+                *open = Ellipse::new(rect.top_left, rect.size);
+            }
+            KerfurMouthType::Line(line) => layout_line(line, layout),
+        }
     }
 }
 
-// Interpolate the diameter of an arc.
-// TODO: Stop being lazy and make a proper function.
-fn interp_diameter(a: &mut u32, b: u32, tick: f32) {
-    let mut size = Size::new(*a, 1);
-    interp_size(&mut size, Size::new(b, 1), tick);
-    *a = size.width;
+/// How far an open mouth drops when fully open, in pixels.
+const OPEN_DEPTH: i32 = 28;
+
+/// How far each side of the mouth moves outward when fully open, in pixels.
+const OPEN_SPREAD: i32 = 4;
+
+/// The smallest sweep of a [`KerfurMouthType::Curve`] when fully open.
+const OPEN_SWEEP: f32 = 3. * FRAC_PI_2;
+
+/// The height of the flat ellipse a [`KerfurMouthType::Line`] morphs through.
+///
+/// Matches the width of the mouth lines in the default styles.
+const LINE_HEIGHT: u32 = 8;
+
+// A flat ellipse covering a line.
+fn line_as_open(line: &Line) -> Ellipse {
+    let (center, delta) = (line.midpoint(), line.delta());
+    let size = Size::new(delta.x.unsigned_abs(), delta.y.unsigned_abs().max(LINE_HEIGHT));
+    Ellipse::with_center(center, size)
+}
+
+// Draw a fang hanging from the middle of a mouth side.
+#[expect(clippy::cast_precision_loss, reason = "Sizes will never be that large")]
+#[expect(clippy::cast_possible_truncation, reason = "Sizes will never be that large")]
+fn draw_fang<D: DrawTarget>(
+    side: &ConstArc,
+    length: u32,
+    display: &mut D,
+    style: &PrimitiveStyle<D::Color>,
+) -> Result<(), D::Error> {
+    if length == 0 {
+        return Ok(());
+    }
+
+    // Point the fang away from the center of the side's circle
+    let angle = side.angle_start + side.angle_sweep / 2.;
+    let (x, y) = (cosf(angle), sinf(angle));
+    let point = |distance: f32, across: f32| {
+        side.center()
+            + Point::new(
+                roundf(x * distance - y * across) as i32,
+                roundf(y * distance + x * across) as i32,
+            )
+    };
+
+    let (radius, length) = (side.diameter as f32 / 2., length as f32);
+    Triangle::new(
+        point(radius, -length / 3.),
+        point(radius, length / 3.),
+        point(radius + length, 0.),
+    )
+    .draw_styled(style, display)
 }
@@ -0,0 +1,726 @@
+use core::{
+    f32::consts::{FRAC_PI_2, FRAC_PI_4, FRAC_PI_6, FRAC_PI_8, PI, TAU},
+    mem::discriminant,
+};
+
+use embedded_graphics::{
+    mock_display::MockDisplay,
+    pixelcolor::BinaryColor,
+    prelude::*,
+    primitives::{Ellipse, Line, PrimitiveStyle, Rectangle, StyledDrawable},
+};
+
+use super::{
+    KerfurElements, KerfurEyeType, KerfurEyebrowType, KerfurEyelids, KerfurHighlights,
+    KerfurMouthType, distance_rectangle, lerp_angle,
+};
+use crate::{
+    KerfurEmote, KerfurExpression, KerfurLayout, KerfurTransform, Transition,
+    animation::SpringState,
+    math::{angle_between, cosf, sinf},
+    primitive::{Caret, ConstArc, ConstSector, Cross, Heart, Star, Swirl},
+    style::BINARY_ON,
+};
+
+const EMOTES: [KerfurEmote; 19] = [
+    KerfurEmote::Neutral,
+    KerfurEmote::Blink,
+    KerfurEmote::NeutralUp,
+    KerfurEmote::NeutralDown,
+    KerfurEmote::NeutralLeft,
+    KerfurEmote::NeutralRight,
+    KerfurEmote::Meow,
+    KerfurEmote::Dazed,
+    KerfurEmote::Dizzy,
+    KerfurEmote::WinkLeft,
+    KerfurEmote::WinkRight,
+    KerfurEmote::Skeptical,
+    KerfurEmote::Smirk,
+    KerfurEmote::Happy,
+    KerfurEmote::Sad,
+    KerfurEmote::Angry,
+    KerfurEmote::Sleepy,
+    KerfurEmote::Surprised,
+    KerfurEmote::Love,
+];
+
+const STEPS: u16 = 64;
+const EPSILON: f32 = 1e-4;
+
+/// A display that elements can be drawn anywhere on, and over each other.
+fn display() -> MockDisplay<BinaryColor> {
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    display.set_allow_out_of_bounds_drawing(true);
+    display
+}
+
+/// Every `(start, sweep)` pair of the arcs and sectors in a set of elements.
+fn angles(elements: &KerfurElements) -> impl Iterator<Item = (f32, f32)> {
+    let mouth = elements.mouth;
+    let eyes = [elements.eye.left, elements.eye.right].into_iter().flat_map(|eye| match eye {
+        KerfurEyeType::Arrow(outer, inner) => [
+            Some((outer.angle_start, outer.angle_sweep)),
+            Some((inner.angle_start, inner.angle_sweep)),
+        ],
+        _ => [None, None],
+    });
+
+    let arc = |arc: ConstArc| Some((arc.angle_start, arc.angle_sweep));
+    let sector = |sector: ConstSector| Some((sector.angle_start, sector.angle_sweep));
+    let mouth_angles = match mouth.mouth {
+        KerfurMouthType::Arcs(left, right, bottom) => [arc(left), arc(right), arc(bottom)],
+        KerfurMouthType::Fangs(left, right, _) => [arc(left), arc(right), None],
+        KerfurMouthType::Tongue(left, right, tongue) => [arc(left), arc(right), sector(tongue)],
+        KerfurMouthType::Curve(curve) => [arc(curve), None, None],
+        KerfurMouthType::Open(_) | KerfurMouthType::Line(_) => [None, None, None],
+    };
+
+    [sector(mouth.nose)].into_iter().chain(mouth_angles).flatten().chain(eyes.flatten())
+}
+
+#[test]
+fn lerp_angle_shortest_path() {
+    // Crossing zero, in both directions
+    assert!(angle_between(0., lerp_angle(0.1, TAU - 0.1, 0.5)).abs() < EPSILON);
+    assert!(angle_between(0., lerp_angle(TAU - 0.1, 0.1, 0.5)).abs() < EPSILON);
+
+    // Equivalent angles do not rotate
+    assert!(angle_between(1., lerp_angle(1., 1. + TAU, 0.5)).abs() < EPSILON);
+    assert!(angle_between(1., lerp_angle(1. - TAU, 1., 0.5)).abs() < EPSILON);
+
+    // Never sweeps more than half a turn in total
+    for a in [0., 1., -PI, 2. * TAU] {
+        for step in 0..=STEPS {
+            let b = f32::from(step) / f32::from(STEPS) * 2. * TAU - TAU;
+            let (mut previous, mut sweep) = (a, 0f32);
+            for t in 1..=STEPS {
+                let angle = lerp_angle(a, b, f32::from(t) / f32::from(STEPS));
+                sweep += angle_between(previous, angle).abs();
+                previous = angle;
+            }
+            assert!(sweep <= PI + EPSILON, "{a} to {b} swept {sweep}");
+        }
+    }
+}
+
+#[test]
+fn lerp_angle_exact_ends() {
+    for (a, b) in [(0., PI), (0.1, TAU - 0.1), (-PI, 3. * PI), (1., -5.)] {
+        assert_eq!(lerp_angle(a, b, 0.).to_bits(), a.to_bits());
+        assert_eq!(lerp_angle(a, b, 1.).to_bits(), b.to_bits());
+    }
+}
+
+#[test]
+fn interpolate_exact_ends() {
+    for a in EMOTES.map(KerfurExpression::into_elements) {
+        for b in EMOTES.map(KerfurExpression::into_elements) {
+            assert!(KerfurElements::interpolate(&a, &b, 0.) == a);
+            assert!(KerfurElements::interpolate(&a, &b, 1.) == b);
+        }
+    }
+}
+
+#[test]
+fn interpolate_angles_shortest_path() {
+    for a in EMOTES.map(KerfurExpression::into_elements) {
+        for b in EMOTES.map(KerfurExpression::into_elements) {
+            // Different mouth types morph through other shapes
+            if discriminant(&a.mouth.mouth) != discriminant(&b.mouth.mouth) {
+                continue;
+            }
+
+            for step in 0..=STEPS {
+                let t = f32::from(step) / f32::from(STEPS);
+                let mid = KerfurElements::interpolate(&a, &b, t);
+
+                for (((a_start, a_sweep), (b_start, b_sweep)), (start, sweep)) in
+                    angles(&a).zip(angles(&b)).zip(angles(&mid))
+                {
+                    // The start angle stays on the shortest arc between the ends
+                    let total = angle_between(a_start, b_start).abs();
+                    assert!(angle_between(a_start, start).abs() <= total + EPSILON);
+                    assert!(angle_between(start, b_start).abs() <= total + EPSILON);
+
+                    // The sweep stays between the ends
+                    assert!(sweep >= a_sweep.min(b_sweep) - EPSILON);
+                    assert!(sweep <= a_sweep.max(b_sweep) + EPSILON);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn blend_clamps_factor() {
+    let (a, b) = (KerfurEmote::Neutral.into_elements(), KerfurEmote::Meow.into_elements());
+    assert!(KerfurElements::blend(a, b, -1.) == a);
+    assert!(KerfurElements::blend(a, b, f32::NAN) == a);
+    assert!(KerfurElements::blend(a, b, 2.) == b);
+    assert!(KerfurElements::blend(a, b, 0.4) == KerfurElements::interpolate(&a, &b, 0.4));
+}
+
+#[test]
+fn look_at_keeps_pupils_inside() {
+    for x in (-480..=960).step_by(60) {
+        for y in (-480..=960).step_by(60) {
+            let target = Point::new(x, y);
+            for elements in [
+                KerfurElements::new().look_at(target),
+                KerfurElements::new().look_at_converging(target),
+            ] {
+                for eye in [elements.eye.left, elements.eye.right] {
+                    let KerfurEyeType::Ellipse(outer, inner) = eye else { unreachable!() };
+                    let offset = inner.center() - outer.center();
+                    let travel = (outer.size - inner.size) / 2;
+                    let (x, y) = (
+                        f64::from(offset.x) / f64::from(travel.width),
+                        f64::from(offset.y) / f64::from(travel.height),
+                    );
+                    assert!(x * x + y * y <= 1., "Pupil outside eye looking at {target:?}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn look_at_converging_crosses_eyes() {
+    let center = KerfurLayout::FACE.center();
+    let pupils = |elements: KerfurElements| {
+        [elements.eye.left, elements.eye.right].map(|eye| {
+            let KerfurEyeType::Ellipse(outer, inner) = eye else { unreachable!() };
+            inner.center() - outer.center()
+        })
+    };
+
+    let [left, right] = pupils(KerfurElements::new().look_at_converging(center));
+    assert!(left.x > 0 && right.x < 0);
+
+    let [left, right] = pupils(KerfurElements::new().look_at(center));
+    assert!(left == right);
+}
+
+/// Eye shapes that aren't used by any emote.
+const SHAPES: [KerfurEyeType; 4] = [
+    KerfurEyeType::Heart(Heart::with_center(Point::new(32, 32), Size::new(40, 36))),
+    KerfurEyeType::Star(Star::with_center(Point::new(32, 32), Size::new_equal(40))),
+    KerfurEyeType::Cross(Cross::with_center(Point::new(32, 32), Size::new_equal(30))),
+    KerfurEyeType::Caret(Caret::with_center(Point::new(32, 32), Size::new(40, 20))),
+];
+
+#[test]
+fn eye_transforms_every_type() {
+    for elements in EMOTES.map(KerfurExpression::into_elements) {
+        for eye in [elements.eye.left, elements.eye.right].into_iter().chain(SHAPES) {
+            // Mirroring twice returns the same eye
+            let twice = KerfurElements::new().with_eyes(eye.mirrored().mirrored(), eye);
+            assert!(
+                KerfurElements::distance(&twice, &KerfurElements::new().with_eyes(eye, eye))
+                    < EPSILON
+            );
+
+            // Scaling by one returns the same eye
+            assert!(eye.with_scale(1.) == eye);
+            assert!(eye.with_pupil_scaled(1.) == eye);
+
+            // Transforms never panic, even with extreme values
+            let _ = eye.with_scale(-1.).with_scale(0.).with_pupil_scaled(-1.);
+            let _ = eye.with_pupil_translated(Point::new(-1000, 1000));
+            let _ = eye.with_pupil_resized(Point::new(i32::MIN, i32::MAX));
+            let _ = eye.translate(Point::new(10, -10));
+            for extreme in [Point::new(i32::MAX, i32::MIN), Point::new(i32::MIN, i32::MAX)] {
+                let _ = eye.with_pupil_translated(extreme).with_pupil_translated(extreme);
+                let _ = eye.with_pupil_resized(extreme).with_pupil_resized(extreme);
+                let _ = eye.translate(extreme).translate(extreme);
+            }
+        }
+
+        // Looking anywhere never panics either
+        for extreme in [Point::new(i32::MAX, i32::MIN), Point::new(i32::MIN, i32::MAX)] {
+            let _ = elements.look_at(extreme).look_at_converging(extreme);
+            let mut eyes = elements.eye;
+            eyes.look(extreme);
+        }
+    }
+}
+
+#[test]
+fn pupil_resized_keeps_center() {
+    let pupil = |eye| match eye {
+        KerfurEyeType::Ellipse(_, inner) => (inner.center(), inner.size),
+        KerfurEyeType::Arrow(_, inner) => (inner.center(), Size::new_equal(inner.diameter)),
+        _ => unreachable!(),
+    };
+
+    for eye in [KerfurEyeType::NEUTRAL_LEFT, KerfurEmote::MEOW.eye.left] {
+        let (center, size) = pupil(eye);
+
+        // Odd amounts keep the center, even though they can't split evenly
+        for by in [Point::new(-3, 5), Point::new(7, -1)] {
+            let (resized_center, resized) = pupil(eye.with_pupil_resized(by));
+            assert_eq!(resized_center, center);
+            assert_eq!(resized.width, size.width.saturating_add_signed(by.x));
+        }
+
+        // Shrinking past zero leaves an empty pupil in the same place
+        let (resized_center, resized) = pupil(eye.with_pupil_resized(Point::new_equal(-1000)));
+        assert_eq!((resized_center, resized), (center, Size::zero()));
+    }
+}
+
+#[test]
+fn eye_mirrored_swaps_sides() {
+    assert!(KerfurEyeType::NEUTRAL_LEFT.mirrored() == KerfurEyeType::NEUTRAL_RIGHT);
+    assert!(KerfurEmote::DIZZY.eye.left.mirrored() == KerfurEmote::DIZZY.eye.right);
+
+    // Mirrored eyes cover the mirror image of their pixels
+    let faces = EMOTES.map(KerfurExpression::into_elements);
+    for eye in faces.iter().flat_map(|face| [face.eye.left, face.eye.right]).chain(SHAPES) {
+        let (bounds, mirrored) = (eye.bounding_box(), eye.mirrored().bounding_box());
+        let right = bounds.bottom_right().unwrap().x;
+        assert_eq!(
+            mirrored,
+            Rectangle::new(Point::new(479 - right, bounds.top_left.y), bounds.size)
+        );
+    }
+}
+
+#[test]
+fn original_faces_keep_their_pixels() {
+    let neutral = KerfurEmote::NEUTRAL;
+    let eye = |center, size| Ellipse::with_center(center, Size::new_equal(size));
+    let eyebrow = |y_inner, y_outer| {
+        KerfurEyebrowType::Line(Line::new(Point::new(278, y_inner), Point::new(312, y_outer)))
+    };
+
+    assert!(
+        KerfurEyeType::BLINK_RIGHT
+            == KerfurEyeType::Line(Line::new(Point::new(288, 240), Point::new(441, 240)))
+    );
+    assert!(
+        KerfurEyeType::NEUTRAL_RIGHT
+            == KerfurEyeType::Ellipse(
+                eye(Point::new(364, 240), 153),
+                eye(Point::new(364, 240), 105)
+            )
+    );
+    assert!(neutral.eyebrow.right == eyebrow(139, 139));
+    assert!(neutral.whisker.right == Line::new(Point::new(446, 302), Point::new(480, 302)));
+    assert!(matches!(
+        neutral.mouth.mouth,
+        KerfurMouthType::Arcs(_, right, bottom)
+            if right == ConstArc::with_center(Point::new(255, 283), 30, PI, -PI)
+                && bottom == ConstArc::with_center(Point::new(240, 307), 20, -FRAC_PI_6, 0.)
+    ));
+
+    assert!(KerfurEmote::DAZED.eyebrow.right == eyebrow(115, 120));
+    assert!(KerfurEmote::DIZZY.eyebrow.right == eyebrow(115, 120));
+    assert!(
+        KerfurEmote::DIZZY.eye.right
+            == KerfurEyeType::Swirl(Swirl::with_center(Point::new(364, 240), PI, 153))
+    );
+
+    let sector = |x, diameter, angle: f32| {
+        ConstSector::with_center(Point::new(x, 235), diameter, TAU - angle, 2. * angle)
+    };
+    assert!(KerfurEmote::MEOW.eyebrow.right == eyebrow(163, 158));
+    assert!(
+        KerfurEmote::MEOW.eye.right
+            == KerfurEyeType::Arrow(sector(259, 360, FRAC_PI_8), sector(316, 264, PI / 10.))
+    );
+}
+
+/// Get the bounds of the eyes, eyebrows, mouth and whiskers of a face.
+fn bounds(elements: &KerfurElements) -> [Rectangle; 7] {
+    [
+        elements.eye.left.bounding_box(),
+        elements.eye.right.bounding_box(),
+        elements.eyebrow.left.bounding_box(),
+        elements.eyebrow.right.bounding_box(),
+        elements.mouth.mouth.bounding_box(),
+        elements.whisker.left.bounding_box(),
+        elements.whisker.right.bounding_box(),
+    ]
+}
+
+#[test]
+fn mirrored_symmetric_faces() {
+    for emote in EMOTES {
+        let elements = emote.into_elements();
+        let mirrored = elements.mirrored();
+
+        // Mirroring twice returns the same face
+        assert!(KerfurElements::distance(&mirrored.mirrored(), &elements) < EPSILON);
+
+        // Symmetric faces mirror onto themselves, but highlights are lit from
+        // the same side on both eyes. The right sides of the original faces
+        // are placed by hand, up to two pixels off their mirror image.
+        if !matches!(
+            emote,
+            KerfurEmote::NeutralLeft
+                | KerfurEmote::NeutralRight
+                | KerfurEmote::WinkLeft
+                | KerfurEmote::WinkRight
+                | KerfurEmote::Skeptical
+                | KerfurEmote::Smirk
+                | KerfurEmote::Love
+        ) {
+            for (a, b) in bounds(&mirrored).iter().zip(&bounds(&elements)) {
+                assert!(distance_rectangle(a, b) <= 2.);
+            }
+        }
+    }
+
+    // Winking with one eye is the mirror image of winking with the other
+    let (left, right) = (KerfurEmote::WINK_LEFT, KerfurEmote::WINK_RIGHT);
+    assert!(KerfurElements::distance(&left.mirrored(), &right) < EPSILON);
+    assert!(KerfurElements::distance(&left, &KerfurEmote::NEUTRAL) > 1.);
+
+    // The neutral mouth's bottom has no sweep, so its mirror image starts at
+    // another angle but still draws the same pixels
+    let KerfurMouthType::Arcs(_, _, bottom) = KerfurMouthType::NEUTRAL else { unreachable!() };
+    let draw = |arc: ConstArc| {
+        let mut display = display();
+        let arc = arc.into_arc().translate(Point::new(-208, -272));
+        arc.draw_styled(&PrimitiveStyle::with_stroke(BinaryColor::On, 1), &mut display).unwrap();
+        display
+    };
+    draw(bottom.mirrored()).assert_eq(&draw(bottom));
+}
+
+#[test]
+fn eyelids_mask_between_lids() {
+    let bounds = Rectangle::new(Point::zero(), Size::new_equal(100));
+
+    // Flat lids cover the top half and bottom quarter
+    let mask = KerfurEyelids::new(0.5, 0.25).mask(&bounds);
+    assert!(!mask(Point::new(50, 10)));
+    assert!(mask(Point::new(50, 60)));
+    assert!(!mask(Point::new(50, 80)));
+
+    // Tilted lids cover more of one side than the other
+    let lids = KerfurEyelids::new(0.5, 0.).with_top_angle(FRAC_PI_4);
+    let (mask, mirrored) = (lids.mask(&bounds), lids.mirrored().mask(&bounds));
+    assert!(mask(Point::new(10, 30)) && !mask(Point::new(90, 70)));
+    assert!(!mirrored(Point::new(10, 70)) && mirrored(Point::new(90, 30)));
+
+    // Lids never tilt past the limit, even when turned with the face
+    let steep = KerfurEyelids::new(0.5, 0.).with_top_angle(FRAC_PI_2);
+    assert!(steep.top_angle() == KerfurEyelids::MAX_ANGLE);
+    let limit = KerfurEyelids::new(0.5, 0.).with_top_angle(KerfurEyelids::MAX_ANGLE);
+    let mut turned = limit;
+    turned.layout(
+        &KerfurLayout::IDENTITY.with_transform(KerfurTransform::IDENTITY.with_rotation(1.)),
+    );
+    let (mask, limit) = (turned.mask(&bounds), limit.mask(&bounds));
+    assert!(bounds.points().all(|point| mask(point) == limit(point)));
+
+    // Open lids never cover anything
+    let mask = KerfurEyelids::OPEN.mask(&bounds);
+    assert!(KerfurEyelids::OPEN.is_open() && bounds.points().all(mask));
+}
+
+#[test]
+fn pupils_clipped_to_eyes() {
+    let eye = KerfurEyeType::Ellipse(
+        Ellipse::with_center(Point::new(24, 32), Size::new_equal(40)),
+        Ellipse::with_center(Point::new(24, 32), Size::new_equal(20)),
+    )
+    .with_pupil_translated(Point::new(20, 0));
+    let elements = KerfurElements::new().with_symmetric_eyes(eye);
+    let outside = Point::new(50, 32);
+
+    for clip in [true, false] {
+        let mut display = display();
+
+        let mut eyes = elements.with_pupil_clipping(clip).eye;
+        eyes.draw(&mut display, &BINARY_ON).unwrap();
+        assert_eq!(display.get_pixel(outside).is_some(), !clip);
+    }
+}
+
+#[test]
+fn highlights_follow_pupils() {
+    let eye = KerfurEyeType::Ellipse(
+        Ellipse::with_center(Point::new(24, 32), Size::new_equal(40)),
+        Ellipse::with_center(Point::new(24, 32), Size::new_equal(24)),
+    );
+    let highlights = KerfurHighlights::new(Point::new(-4, -4), 5);
+    let elements =
+        KerfurElements::new().with_symmetric_eyes(eye).with_highlights(highlights, highlights);
+
+    for by in [Point::zero(), Point::new(6, 2)] {
+        let mut display = display();
+
+        let mut eyes = elements.eye;
+        eyes.look(by);
+        eyes.draw(&mut display, &BINARY_ON).unwrap();
+
+        // The highlight is drawn over the pupil, wherever the pupil is
+        let highlight = Point::new(20, 28) + by;
+        assert_eq!(display.get_pixel(highlight), Some(BinaryColor::On));
+        assert_eq!(display.get_pixel(highlight + Point::new(0, 6)), Some(BinaryColor::Off));
+    }
+}
+
+#[test]
+fn mouth_types_morph() {
+    let side = ConstArc::with_center(Point::new(225, 283), 30, 0., PI);
+    let mouths = [
+        KerfurMouthType::NEUTRAL,
+        KerfurMouthType::Fangs(side, side.mirrored(), 12),
+        KerfurMouthType::Tongue(
+            side,
+            side.mirrored(),
+            ConstSector::with_center(Point::new(240, 300), 30, 0., PI),
+        ),
+        KerfurMouthType::Curve(ConstArc::with_center(Point::new(240, 300), 40, PI, PI)),
+        KerfurMouthType::Open(Ellipse::with_center(Point::new(240, 300), Size::new(30, 40))),
+        KerfurMouthType::Line(Line::new(Point::new(220, 300), Point::new(260, 300))),
+    ];
+
+    for a in mouths.map(|mouth| KerfurElements::new().with_mouth(mouth)) {
+        // Moving the nose and mouth never panics, even with extreme values
+        for extreme in [Point::new(i32::MAX, i32::MIN), Point::new(i32::MIN, i32::MAX)] {
+            let mut moved = a.mouth;
+            moved.translate(extreme);
+            moved.translate(extreme);
+        }
+
+        for b in mouths.map(|mouth| KerfurElements::new().with_mouth(mouth)) {
+            // Every pair of mouths morphs exactly between its ends
+            assert!(KerfurElements::interpolate(&a, &b, 0.) == a);
+            assert!(KerfurElements::interpolate(&a, &b, 1.) == b);
+
+            // Mouths with sides keep moving their sides the whole way
+            if let (KerfurMouthType::Fangs(..), KerfurMouthType::Tongue(..)) =
+                (a.mouth.mouth, b.mouth.mouth)
+            {
+                let mid = KerfurElements::interpolate(&a, &b, 0.5);
+                assert!(
+                    matches!(mid.mouth.mouth, KerfurMouthType::Tongue(left, ..) if left == side)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn open_mouth_only_moves_mouth() {
+    for closed in EMOTES.map(KerfurExpression::into_elements) {
+        let mut unchanged = closed;
+        unchanged.open_mouth(0.);
+        assert!(unchanged == closed);
+
+        // Opening the mouth leaves every other element where it was
+        let mut open = closed;
+        open.open_mouth(1.);
+        assert!(open.mouth.mouth != closed.mouth.mouth);
+        assert!(open.with_mouth(closed.mouth.mouth) == closed);
+    }
+}
+
+#[test]
+fn eye_shapes_stay_inside_bounds() {
+    for eye in SHAPES {
+        let mut display = display();
+
+        let mut eyes = KerfurElements::new().with_eyes(eye, eye).eye;
+        eyes.draw(&mut display, &BINARY_ON).unwrap();
+
+        // Shapes stay inside their bounds, apart from the stroke of lines
+        let bounds = eye.bounding_box().offset(BINARY_ON.left_eye_line.stroke_width.cast_signed());
+        let drawn = display.affected_area();
+        assert!(!drawn.is_zero_sized());
+        assert!(bounds.contains(drawn.top_left) && bounds.contains(drawn.bottom_right().unwrap()));
+
+        // Every shape is drawn through its center
+        assert_eq!(display.get_pixel(eye.bounding_box().center()), Some(BinaryColor::On));
+
+        // Shapes of the same type morph directly between sizes
+        let small = eye.with_scale(0.5);
+        let eyes = |eye| KerfurElements::new().with_eyes(eye, eye);
+        let mid = KerfurElements::interpolate(&eyes(eye), &eyes(small), 0.5);
+        assert!(discriminant(&mid.eye.left) == discriminant(&eye));
+        assert!(mid.eye.left.bounding_box().size.width < eye.bounding_box().size.width);
+    }
+}
+
+/// Get the point in the middle of an eyebrow.
+#[expect(clippy::cast_precision_loss, reason = "Diameters will never be that large")]
+#[expect(clippy::cast_possible_truncation, reason = "Positions will never be that large")]
+fn eyebrow_middle(eyebrow: KerfurEyebrowType) -> Point {
+    match eyebrow {
+        KerfurEyebrowType::Line(line) => line.midpoint(),
+        KerfurEyebrowType::Arc(arc) => {
+            let (angle, radius) =
+                (arc.angle_start + arc.angle_sweep / 2., arc.diameter as f32 / 2.);
+            arc.center() + Point::new((cosf(angle) * radius) as i32, (sinf(angle) * radius) as i32)
+        }
+    }
+}
+
+#[test]
+fn eyebrows_bend_between_lines_and_arcs() {
+    let curved = ConstArc::with_center(Point::new(180, 150), 80, PI + FRAC_PI_4, FRAC_PI_2);
+    let (a, b) =
+        (KerfurElements::new(), KerfurElements::new().with_symmetric_curved_eyebrows(curved));
+
+    for (a, b) in [(a, b), (b, a)] {
+        assert!(KerfurElements::interpolate(&a, &b, 0.) == a);
+        assert!(KerfurElements::interpolate(&a, &b, 1.) == b);
+
+        // The middle of the eyebrow moves straight between both ends
+        let (start, end) = (eyebrow_middle(a.eyebrow.left), eyebrow_middle(b.eyebrow.left));
+        let bounds = Rectangle::with_corners(start, end).offset(2);
+        for step in 0..=STEPS {
+            let mid = KerfurElements::interpolate(&a, &b, f32::from(step) / f32::from(STEPS));
+            assert!(bounds.contains(eyebrow_middle(mid.eyebrow.left)));
+        }
+
+        // Moving the eyebrows never panics, even with extreme values
+        for extreme in [Point::new(i32::MAX, i32::MIN), Point::new(i32::MIN, i32::MAX)] {
+            let mut moved = a.eyebrow;
+            moved.translate(extreme);
+            moved.translate(extreme);
+        }
+    }
+}
+
+#[test]
+fn whiskers_fade_and_twitch() {
+    let (a, b) = (
+        KerfurElements::new(),
+        KerfurElements::new()
+            .with_whisker_settings(Point::new(0, 16), 4)
+            .with_whisker_angles(0.1, 0.1),
+    );
+
+    // Whiskers fade in one at a time, instead of all appearing at once
+    let mut previous = a.whisker.count;
+    for step in 0..=STEPS {
+        let mid = KerfurElements::interpolate(&a, &b, f32::from(step) / f32::from(STEPS));
+        assert!(mid.whisker.count >= previous && mid.whisker.count - previous < 0.1);
+        previous = mid.whisker.count;
+    }
+    assert!(KerfurElements::distance(&a, &b) > 0.);
+
+    // Twitching starts and ends with the whiskers in place
+    for elements in [a, b] {
+        for (progress, moved) in [(0., false), (0.1, true), (1., false)] {
+            let mut twitched = elements;
+            twitched.twitch_whiskers(progress);
+            assert_eq!(twitched != elements, moved);
+        }
+    }
+}
+
+#[test]
+fn face_transform_moves_every_element() {
+    let layout = KerfurLayout::with_size(Size::new(320, 240));
+    for elements in EMOTES.map(KerfurExpression::into_elements) {
+        // Moving the face is the same as moving every element
+        let transform = KerfurTransform::IDENTITY.with_translation(Point::new(12, -8));
+        let mut moved = elements;
+        moved.translate(Point::new(12, -8));
+        assert!(elements.with_layout(&KerfurLayout::IDENTITY.with_transform(transform)) == moved);
+
+        // Tilting the face rotates every angle with it
+        let transform = KerfurTransform::IDENTITY.with_rotation(0.2);
+        let tilted = elements.with_layout(&layout.with_transform(transform));
+        for ((a, a_sweep), (b, b_sweep)) in
+            angles(&elements.with_layout(&layout)).zip(angles(&tilted))
+        {
+            assert!((angle_between(a, b) - 0.2).abs() < EPSILON);
+            assert!((a_sweep - b_sweep).abs() < EPSILON);
+        }
+    }
+
+    // Points on the display still map back onto the canvas
+    let transform = KerfurTransform::IDENTITY
+        .with_translation(Point::new(6, 10))
+        .with_scale_xy(1.2, 0.8)
+        .with_rotation(-0.1);
+    let layout = layout.with_transform(transform);
+    for point in [Point::new(80, 150), Point::new(240, 240), Point::new(400, 330)] {
+        let distance = layout.canvas_point(layout.point(point)) - point;
+        assert!(distance.x.abs() <= 1 && distance.y.abs() <= 1);
+    }
+}
+
+/// Start springs at rest, pulling one set of elements toward another.
+fn spring(a: &KerfurElements, b: &KerfurElements) -> SpringState {
+    SpringState::REST.retarget((a, a), a, b)
+}
+
+#[test]
+fn springs_overshoot_and_settle() {
+    let (a, b) = (KerfurEmote::NEUTRAL, KerfurEmote::SURPRISED);
+    let settle = |transition| {
+        let Transition::Spring(stiffness, damping) = transition else { unreachable!() };
+        let (mut spring, mut furthest) = (spring(&a, &b), 0f32);
+        for frame in 1..=600 {
+            let settled = spring.step(stiffness, damping, 1. / 60.);
+            furthest = spring.positions().into_iter().fold(furthest, f32::max);
+            if settled.is_some() {
+                // Settled springs snap onto the target
+                assert!(KerfurElements::interpolate_parts(&a, &b, spring.positions()) == b);
+                return (frame, furthest);
+            }
+        }
+        panic!("{transition:?} never settled");
+    };
+
+    // Bouncy springs overshoot the target, critically damped springs don't
+    let (_, furthest) = settle(Transition::BOUNCY);
+    assert!(furthest > 1.1);
+    let (_, furthest) = settle(Transition::critical_spring(400.));
+    assert!(furthest <= 1.);
+
+    // Heavily damped springs take longer to settle
+    let (critical, _) = settle(Transition::critical_spring(400.));
+    let (heavy, _) = settle(Transition::spring(400., 120.));
+    assert!(critical < heavy);
+
+    // Overshooting elements still interpolate
+    assert!(KerfurElements::interpolate(&a, &b, 1.2) != b);
+
+    // Long frames only move the springs as far as a tenth of a second
+    let (mut long, mut short) = (spring(&a, &b), spring(&a, &b));
+    long.step(400., 16., 1e9);
+    short.step(400., 16., 0.1);
+    assert!(long == short);
+}
+
+#[test]
+fn springs_keep_their_speed() {
+    // Morphing between mouth types only moves their bounds
+    let a = KerfurEmote::NEUTRAL;
+    let b = KerfurEmote::SURPRISED.with_mouth(KerfurMouthType::NEUTRAL);
+    let Transition::Spring(stiffness, damping) = Transition::BOUNCY else { unreachable!() };
+    let mut moving = spring(&a, &b);
+    for _ in 0..3 {
+        moving.step(stiffness, damping, 1. / 60.);
+    }
+    let current = KerfurElements::interpolate_parts(&a, &b, moving.positions());
+    let moved = KerfurElements::distances(&a, &b);
+
+    // Heading further the same way, moving parts get ahead of resting ones
+    let further = KerfurElements::interpolate(&a, &b, 2.);
+    let (mut kept, mut rest) =
+        (moving.retarget((&a, &b), &current, &further), spring(&current, &further));
+    kept.step(stiffness, damping, 1. / 60.);
+    rest.step(stiffness, damping, 1. / 60.);
+    for ((kept, rest), moved) in kept.positions().into_iter().zip(rest.positions()).zip(moved) {
+        assert!(if moved > 1. { kept > rest } else { (kept - rest).abs() < EPSILON });
+    }
+
+    // Heading back, moving parts overshoot the way they were going
+    let mut back = moving.retarget((&a, &b), &current, &a);
+    back.step(stiffness, damping, 1. / 60.);
+    for (back, moved) in back.positions().into_iter().zip(moved) {
+        assert!(moved <= 1. || back < 0.);
+    }
+}
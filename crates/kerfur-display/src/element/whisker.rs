@@ -1,45 +1,127 @@
+use core::f32::consts::TAU;
+
 use embedded_graphics::{
     prelude::*,
     primitives::{Line, PrimitiveStyle, StyledDrawable},
 };
 
-use crate::{KerfurStyle, element::interp_line};
+use crate::{
+    KerfurLayout, KerfurStyle,
+    element::{distance_line, distance_point, layout_line, lerp, lerp_line, lerp_point},
+    math::{ceilf, cosf, roundf, sinf, sqrtf},
+};
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq)]
 pub(super) struct WhiskerState {
     pub(super) left: Line,
     pub(super) right: Line,
     pub(super) offset: Point,
-    pub(super) count: u32,
+    pub(super) count: f32,
+    pub(super) angle: f32,
+    pub(super) spread: f32,
 }
 
+/// How far the whiskers tilt at the start of a twitch, in radians.
+const TWITCH_ANGLE: f32 = 0.2;
+
+/// How many times the whiskers flick back and forth during a twitch.
+const TWITCH_FLICKS: f32 = 3.;
+
 impl WhiskerState {
+    /// The number of parts of the whiskers that move separately.
+    pub(super) const PARTS: usize = 3;
+
     pub(super) fn draw<D: DrawTargetExt>(
         &mut self,
         display: &mut D,
         style: &KerfurStyle<D::Color>,
     ) -> Result<(), D::Error> {
-        Self::draw_whisker(self.left, self.offset, self.count, display, &style.whisker)?;
-        Self::draw_whisker(self.right, self.offset, self.count, display, &style.whisker)?;
+        // Mirror the tilt on the right, so both sides tilt the same way
+        self.draw_whisker(self.left, -1., display, &style.whisker)?;
+        self.draw_whisker(self.right, 1., display, &style.whisker)?;
         Ok(())
     }
 
+    #[expect(clippy::cast_precision_loss, reason = "Whisker counts will never be that large")]
+    #[expect(clippy::cast_possible_truncation, reason = "Whisker counts will never be that large")]
+    #[expect(clippy::cast_sign_loss, reason = "Whisker counts are clamped to zero")]
     fn draw_whisker<D: DrawTargetExt>(
+        &self,
         mut whisker: Line,
-        offset: Point,
-        count: u32,
+        direction: f32,
         display: &mut D,
         style: &PrimitiveStyle<D::Color>,
     ) -> Result<(), D::Error> {
-        for _ in 0..count {
-            whisker.draw_styled(style, display)?;
-            whisker.translate_mut(offset);
+        for index in 0..ceilf(self.count).max(0.) as u32 {
+            // Partial whiskers are shorter, so they grow in and shrink away
+            let (index, length) = (index as f32, (self.count - index as f32).min(1.));
+            let angle = direction * (self.angle + self.spread * index);
+            tilted(&whisker, angle, length).draw_styled(style, display)?;
+            whisker.translate_mut(self.offset);
         }
         Ok(())
     }
 
-    pub(super) fn interpolate(&mut self, other: &Self, tick: f32) {
-        interp_line(&mut self.left, &other.left, tick);
-        interp_line(&mut self.right, &other.right, tick);
+    pub(super) fn translate(&mut self, by: Point) {
+        self.left.translate_mut(by);
+        self.right.translate_mut(by);
+    }
+
+    /// Tilt the whiskers for part of a twitch.
+    ///
+    /// A `progress` of `0.0` is the start of the twitch and `1.0` is the end,
+    /// where the whiskers have settled back into place.
+    pub(super) fn twitch(&mut self, progress: f32) {
+        let progress = progress.clamp(0., 1.);
+        self.angle += TWITCH_ANGLE * (1. - progress) * sinf(progress * TWITCH_FLICKS * TAU);
+    }
+
+    /// Get the distance the left and right whiskers move,
+    /// and the distance the rest of the whiskers fan out by.
+    pub(super) fn distances(a: &Self, b: &Self) -> [f32; Self::PARTS] {
+        // Whiskers further from the first one move further
+        let length = length(&a.left).max(length(&b.left));
+        let count = a.count.max(b.count).max(1.) - 1.;
+        let fan = (distance_point(a.offset, b.offset) * count)
+            .max((b.count - a.count).abs() * length)
+            .max(((b.angle - a.angle).abs() + (b.spread - a.spread).abs() * count) * length);
+        [distance_line(&a.left, &b.left), distance_line(&a.right, &b.right), fan]
     }
+
+    /// Interpolate each part of the whiskers by its own progress,
+    /// in the same order as [`WhiskerState::distances`].
+    pub(super) fn interpolate(a: &Self, b: &Self, [left, right, fan]: [f32; Self::PARTS]) -> Self {
+        Self {
+            left: lerp_line(&a.left, &b.left, left),
+            right: lerp_line(&a.right, &b.right, right),
+            offset: lerp_point(a.offset, b.offset, fan),
+            count: lerp(a.count, b.count, fan),
+            angle: lerp(a.angle, b.angle, fan),
+            spread: lerp(a.spread, b.spread, fan),
+        }
+    }
+
+    pub(super) fn layout(&mut self, layout: &KerfurLayout) {
+        layout_line(&mut self.left, layout);
+        layout_line(&mut self.right, layout);
+        self.offset = layout.vector(self.offset);
+    }
+}
+
+// The length of a whisker.
+#[expect(clippy::cast_precision_loss, reason = "Positions will never be that large")]
+fn length(line: &Line) -> f32 {
+    let delta = line.delta();
+    sqrtf((delta.x * delta.x + delta.y * delta.y) as f32)
+}
+
+// A whisker rotated around its inner end, and shortened to part of its length.
+#[expect(clippy::cast_precision_loss, reason = "Positions will never be that large")]
+#[expect(clippy::cast_possible_truncation, reason = "Positions will never be that large")]
+fn tilted(whisker: &Line, angle: f32, length: f32) -> Line {
+    let delta = whisker.delta();
+    let (x, y) = (delta.x as f32 * length, delta.y as f32 * length);
+    let (sin, cos) = (sinf(angle), cosf(angle));
+    let end = Point::new(roundf(x * cos - y * sin) as i32, roundf(x * sin + y * cos) as i32);
+    Line::new(whisker.start, whisker.start + end)
 }
@@ -1,11 +1,14 @@
-use core::f32::consts::{FRAC_PI_6, FRAC_PI_8, PI};
+use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, FRAC_PI_6, FRAC_PI_8, PI};
 
-use embedded_graphics::{prelude::*, primitives::Line};
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Ellipse, Line},
+};
 
 use crate::{
     KerfurElements,
-    element::KerfurEyeType,
-    primitive::{ConstArc, ConstSector},
+    element::{KerfurEyeType, KerfurEyelids, KerfurHighlights, KerfurMouthType},
+    primitive::{ConstArc, ConstSector, Swirl, mirror_line},
 };
 
 /// A set of default Kerfur expressions.
@@ -30,6 +33,30 @@ pub enum KerfurEmote {
     Meow,
     /// A dazed face
     Dazed,
+    /// A dizzy face
+    Dizzy,
+
+    /// A face winking with the left eye
+    WinkLeft,
+    /// A face winking with the right eye
+    WinkRight,
+    /// A skeptical face, with one eyebrow raised
+    Skeptical,
+    /// A smirking face, with one side of the mouth lifted
+    Smirk,
+
+    /// A happy face, with closed arcs for eyes
+    Happy,
+    /// A sad face, with drooping eyebrows
+    Sad,
+    /// An angry face, with slanted eyebrows and narrowed eyes
+    Angry,
+    /// A sleepy face, with half-closed eyes
+    Sleepy,
+    /// A surprised face, with wide eyes and an open mouth
+    Surprised,
+    /// A loving face, with large pupils
+    Love,
 }
 
 impl KerfurExpression for KerfurEmote {
@@ -43,6 +70,17 @@ impl KerfurExpression for KerfurEmote {
             KerfurEmote::NeutralRight => Self::NEUTRAL_RIGHT,
             KerfurEmote::Meow => Self::MEOW,
             KerfurEmote::Dazed => Self::DAZED,
+            KerfurEmote::Dizzy => Self::DIZZY,
+            KerfurEmote::WinkLeft => Self::WINK_LEFT,
+            KerfurEmote::WinkRight => Self::WINK_RIGHT,
+            KerfurEmote::Skeptical => Self::SKEPTICAL,
+            KerfurEmote::Smirk => Self::SMIRK,
+            KerfurEmote::Happy => Self::HAPPY,
+            KerfurEmote::Sad => Self::SAD,
+            KerfurEmote::Angry => Self::ANGRY,
+            KerfurEmote::Sleepy => Self::SLEEPY,
+            KerfurEmote::Surprised => Self::SURPRISED,
+            KerfurEmote::Love => Self::LOVE,
         }
     }
 }
@@ -87,6 +125,24 @@ impl KerfurEmote {
             Point::new(480 * 65 / 100, 480 * 25 / 100),
         ),
     );
+    /// The [`KerfurElements`] for the [`KerfurEmote::Dizzy`] emote.
+    pub const DIZZY: KerfurElements = KerfurElements::new()
+        .with_symmetric_eyes(KerfurEyeType::Swirl(Swirl::with_center(
+            Point::new(480 * 24 / 100, 240),
+            0.,
+            480 * 32 / 100,
+        )))
+        .with_eye_spin(6.0)
+        .with_eyebrows(
+            Line::new(
+                Point::new(480 * 42 / 100, 480 * 24 / 100),
+                Point::new(480 * 35 / 100, 480 * 25 / 100),
+            ),
+            Line::new(
+                Point::new(480 * 58 / 100, 480 * 24 / 100),
+                Point::new(480 * 65 / 100, 480 * 25 / 100),
+            ),
+        );
     /// The [`KerfurElements`] for the [`KerfurEmote::Meow`] emote.
     pub const MEOW: KerfurElements = KerfurElements::new()
         .with_eyes(
@@ -137,6 +193,133 @@ impl KerfurEmote {
         ));
 }
 
+impl KerfurEmote {
+    /// A left mouth side with its outer end lifted,
+    /// used by the one-sided emotes.
+    const LIFTED_MOUTH: ConstArc =
+        ConstArc::with_center(Point::new(225, 480 * 58 / 100), 30, FRAC_PI_8, PI);
+    /// The [`KerfurElements`] for the [`KerfurEmote::Skeptical`] emote.
+    pub const SKEPTICAL: KerfurElements = KerfurElements::new().with_eyebrows(
+        Line::new(
+            Point::new(480 * 42 / 100, 480 * 31 / 100),
+            Point::new(480 * 35 / 100, 480 * 30 / 100),
+        ),
+        mirror_line(Line::new(
+            Point::new(480 * 42 / 100, 480 * 25 / 100),
+            Point::new(480 * 35 / 100, 480 * 22 / 100),
+        )),
+    );
+    /// The [`KerfurElements`] for the [`KerfurEmote::Smirk`] emote.
+    pub const SMIRK: KerfurElements = KerfurElements::new().with_mouth_sides(
+        ConstArc::with_center(Point::new(225, 480 * 59 / 100), 30, 0., PI),
+        Self::LIFTED_MOUTH.mirrored(),
+    );
+    /// The [`KerfurElements`] for the [`KerfurEmote::WinkLeft`] emote.
+    pub const WINK_LEFT: KerfurElements = KerfurElements::new()
+        .with_eyes(KerfurEyeType::BLINK_LEFT, KerfurEyeType::NEUTRAL_RIGHT)
+        .with_eyebrows(
+            Line::new(
+                Point::new(480 * 42 / 100, 480 * 31 / 100),
+                Point::new(480 * 35 / 100, 480 * 32 / 100),
+            ),
+            mirror_line(Line::new(
+                Point::new(480 * 42 / 100, 480 * 29 / 100),
+                Point::new(480 * 35 / 100, 480 * 29 / 100),
+            )),
+        )
+        .with_mouth_sides(
+            Self::LIFTED_MOUTH,
+            ConstArc::with_center(Point::new(225, 480 * 59 / 100), 30, 0., PI).mirrored(),
+        );
+    /// The [`KerfurElements`] for the [`KerfurEmote::WinkRight`] emote.
+    pub const WINK_RIGHT: KerfurElements = Self::WINK_LEFT.mirrored();
+}
+
+impl KerfurEmote {
+    /// The [`KerfurElements`] for the [`KerfurEmote::Angry`] emote.
+    pub const ANGRY: KerfurElements = KerfurElements::new()
+        .with_symmetric_eyes(KerfurEyeType::Ellipse(
+            Ellipse::with_center(
+                Point::new(480 * 24 / 100, 250),
+                Size::new(480 * 32 / 100, 480 * 20 / 100),
+            ),
+            Ellipse::with_center(
+                Point::new(480 * 24 / 100, 250),
+                Size::new(480 * 20 / 100, 480 * 14 / 100),
+            ),
+        ))
+        .with_symmetric_eyebrows(Line::new(
+            Point::new(480 * 42 / 100, 480 * 36 / 100),
+            Point::new(480 * 33 / 100, 480 * 30 / 100),
+        ))
+        .with_mouth(KerfurMouthType::Fangs(Self::FLAT_MOUTH, Self::FLAT_MOUTH.mirrored(), 14));
+    /// A left mouth side with a shallow curve, used by the angry emote.
+    const FLAT_MOUTH: ConstArc =
+        ConstArc::with_center(Point::new(225, 480 * 59 / 100), 30, FRAC_PI_4, FRAC_PI_2);
+    /// The [`KerfurElements`] for the [`KerfurEmote::Happy`] emote.
+    pub const HAPPY: KerfurElements = KerfurElements::new()
+        .with_symmetric_eyes(KerfurEyeType::Arrow(
+            ConstSector::with_center(Point::new(480 * 24 / 100, 250), 480 * 32 / 100, PI, PI),
+            ConstSector::with_center(Point::new(480 * 24 / 100, 266), 480 * 32 / 100, PI, PI),
+        ))
+        .with_symmetric_eyebrows(Line::new(
+            Point::new(480 * 42 / 100, 480 * 27 / 100),
+            Point::new(480 * 35 / 100, 480 * 26 / 100),
+        ));
+    /// The [`KerfurElements`] for the [`KerfurEmote::Love`] emote.
+    pub const LOVE: KerfurElements = KerfurElements::new()
+        .with_symmetric_eyes(KerfurEyeType::NEUTRAL_LEFT.with_pupil_resized(Point::new(24, 24)))
+        .with_highlights(Self::LOVE_HIGHLIGHTS, Self::LOVE_HIGHLIGHTS)
+        .with_symmetric_eyebrows(Line::new(
+            Point::new(480 * 42 / 100, 480 * 27 / 100),
+            Point::new(480 * 35 / 100, 480 * 26 / 100),
+        ))
+        .with_mouth_bottom(ConstArc::with_center(
+            Point::new(240, 480 * 64 / 100),
+            30,
+            -FRAC_PI_6,
+            PI + 2. * FRAC_PI_6,
+        ));
+    /// Bright highlights on the pupils of the [`KerfurEmote::Love`] emote.
+    const LOVE_HIGHLIGHTS: KerfurHighlights =
+        KerfurHighlights::new(Point::new(-24, -24), 36).with_second(Point::new(22, 24), 14);
+    /// The [`KerfurElements`] for the [`KerfurEmote::Sad`] emote.
+    pub const SAD: KerfurElements = KerfurElements::new()
+        .with_symmetric_eyes(KerfurEyeType::NEUTRAL_LEFT.with_pupil_translated(Point::new(0, 10)))
+        .with_symmetric_eyebrows(Line::new(
+            Point::new(480 * 42 / 100, 480 * 24 / 100),
+            Point::new(480 * 33 / 100, 480 * 29 / 100),
+        ))
+        .with_mouth(KerfurMouthType::Curve(ConstArc::with_center(
+            Point::new(240, 480 * 64 / 100),
+            48,
+            PI + FRAC_PI_4,
+            FRAC_PI_2,
+        )));
+    /// The [`KerfurElements`] for the [`KerfurEmote::Sleepy`] emote.
+    pub const SLEEPY: KerfurElements = KerfurElements::new()
+        .with_symmetric_eyes(KerfurEyeType::NEUTRAL_LEFT.with_pupil_translated(Point::new(0, 16)))
+        .with_symmetric_eyelids(KerfurEyelids::new(0.5, 0.))
+        .with_symmetric_eyebrows(Line::new(
+            Point::new(480 * 42 / 100, 480 * 32 / 100),
+            Point::new(480 * 35 / 100, 480 * 32 / 100),
+        ));
+    /// The [`KerfurElements`] for the [`KerfurEmote::Surprised`] emote.
+    pub const SURPRISED: KerfurElements = KerfurElements::new()
+        .with_symmetric_eyes(KerfurEyeType::Ellipse(
+            Ellipse::with_center(Point::new(480 * 24 / 100, 240), Size::new_equal(480 * 36 / 100)),
+            Ellipse::with_center(Point::new(480 * 24 / 100, 240), Size::new_equal(480 * 12 / 100)),
+        ))
+        .with_symmetric_eyebrows(Line::new(
+            Point::new(480 * 42 / 100, 480 * 21 / 100),
+            Point::new(480 * 35 / 100, 480 * 20 / 100),
+        ))
+        .with_mouth(KerfurMouthType::Open(Ellipse::with_center(
+            Point::new(240, 480 * 64 / 100),
+            Size::new(36, 44),
+        )));
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// A trait for types that can become a set of facial elements.
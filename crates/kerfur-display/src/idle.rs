@@ -0,0 +1,254 @@
+//! Procedural idle behaviour, layered on top of any expression.
+
+use core::{f32::consts::TAU, time::Duration};
+
+use embedded_graphics::prelude::Point;
+
+use crate::{
+    KerfurElements,
+    math::{cosf, roundf, sinf, wrap_angle},
+};
+
+/// Small movements that make Kerfur look alive while idle.
+///
+/// [`Idle`] randomly blinks, makes small gaze saccades,
+/// and slowly bobs the face as if breathing.
+/// These are applied on top of whatever expression is being shown,
+/// and never change the expression itself.
+///
+/// The movements are random, but the same seed will always produce the
+/// same movements when drawn with the same elapsed times.
+///
+/// ```rust
+/// use core::time::Duration;
+///
+/// use kerfur_display::Idle;
+///
+/// let idle = Idle::new(0x4B45_5246)
+///     .with_blink_interval(Duration::from_secs(2), Duration::from_secs(5))
+///     .with_breathing(false);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Idle {
+    rng: Rng,
+
+    blinking: bool,
+    blink_interval: (Duration, Duration),
+    until_blink: Duration,
+    blink: Option<Duration>,
+
+    saccades: bool,
+    saccade_interval: (Duration, Duration),
+    saccade_distance: u32,
+    until_saccade: Duration,
+    saccade: Duration,
+    gaze_from: Point,
+    gaze_to: Point,
+
+    breathing: bool,
+    breathing_amplitude: u32,
+    breath: f32,
+}
+
+impl Idle {
+    /// How long a blink takes, from open to closed to open again.
+    pub const BLINK_DURATION: Duration = Duration::from_millis(160);
+    /// How long a single breath takes.
+    pub const BREATH_DURATION: Duration = Duration::from_secs(4);
+    /// How long a saccade takes to move the gaze.
+    pub const SACCADE_DURATION: Duration = Duration::from_millis(40);
+
+    /// Create a new [`Idle`] using the given random seed.
+    #[must_use]
+    pub const fn new(seed: u32) -> Self {
+        let blink_interval = (Duration::from_secs(2), Duration::from_secs(6));
+        let saccade_interval = (Duration::from_millis(500), Duration::from_secs(3));
+
+        Self {
+            rng: Rng::new(seed),
+
+            blinking: true,
+            blink_interval,
+            until_blink: blink_interval.0,
+            blink: None,
+
+            saccades: true,
+            saccade_interval,
+            saccade_distance: 8,
+            until_saccade: saccade_interval.0,
+            saccade: Self::SACCADE_DURATION,
+            gaze_from: Point::zero(),
+            gaze_to: Point::zero(),
+
+            breathing: true,
+            breathing_amplitude: 3,
+            breath: 0.,
+        }
+    }
+
+    /// Enable or disable blinking.
+    #[inline]
+    #[must_use]
+    pub const fn with_blinking(mut self, blinking: bool) -> Self {
+        self.blinking = blinking;
+        self
+    }
+
+    /// Set the shortest and longest time between blinks.
+    #[inline]
+    #[must_use]
+    pub const fn with_blink_interval(mut self, min: Duration, max: Duration) -> Self {
+        self.blink_interval = (min, max);
+        self.until_blink = min;
+        self
+    }
+
+    /// Enable or disable gaze saccades.
+    #[inline]
+    #[must_use]
+    pub const fn with_saccades(mut self, saccades: bool) -> Self {
+        self.saccades = saccades;
+        self
+    }
+
+    /// Set the shortest and longest time between gaze saccades.
+    #[inline]
+    #[must_use]
+    pub const fn with_saccade_interval(mut self, min: Duration, max: Duration) -> Self {
+        self.saccade_interval = (min, max);
+        self.until_saccade = min;
+        self
+    }
+
+    /// Set the furthest distance the pupils move during a saccade, in pixels.
+    #[inline]
+    #[must_use]
+    pub const fn with_saccade_distance(mut self, distance: u32) -> Self {
+        self.saccade_distance = distance;
+        self
+    }
+
+    /// Enable or disable breathing.
+    #[inline]
+    #[must_use]
+    pub const fn with_breathing(mut self, breathing: bool) -> Self {
+        self.breathing = breathing;
+        self
+    }
+
+    /// Set how far the face moves up and down while breathing, in pixels.
+    #[inline]
+    #[must_use]
+    pub const fn with_breathing_amplitude(mut self, amplitude: u32) -> Self {
+        self.breathing_amplitude = amplitude;
+        self
+    }
+
+    /// Advance the idle movements by the elapsed time.
+    pub(crate) fn update(&mut self, elapsed: Duration) {
+        // Blink, then wait a random amount of time before blinking again
+        if let Some(blink) = &mut self.blink {
+            *blink += elapsed;
+            if *blink >= Self::BLINK_DURATION {
+                self.blink = None;
+                self.until_blink = self.rng.next_duration(self.blink_interval);
+            }
+        } else if let Some(until) = self.until_blink.checked_sub(elapsed) {
+            self.until_blink = until;
+        } else {
+            self.blink = Some(Duration::ZERO);
+        }
+
+        // Glance somewhere nearby, then wait before glancing again
+        self.saccade = (self.saccade + elapsed).min(Self::SACCADE_DURATION);
+        if let Some(until) = self.until_saccade.checked_sub(elapsed) {
+            self.until_saccade = until;
+        } else {
+            self.gaze_from = self.gaze();
+            self.gaze_to = self.rng.next_point(self.saccade_distance);
+            self.saccade = Duration::ZERO;
+            self.until_saccade = self.rng.next_duration(self.saccade_interval);
+        }
+
+        // Breathe continuously
+        let breaths = elapsed.as_secs_f32() / Self::BREATH_DURATION.as_secs_f32();
+        self.breath = wrap_angle(self.breath + breaths * TAU);
+    }
+
+    /// Apply the idle movements to a set of elements.
+    #[expect(clippy::cast_precision_loss, reason = "Amplitudes will never be that large")]
+    #[expect(clippy::cast_possible_truncation, reason = "Amplitudes will never be that large")]
+    pub(crate) fn apply(&self, elements: &mut KerfurElements) {
+        if self.saccades {
+            elements.look(self.gaze());
+        }
+
+        if self.blinking
+            && let Some(blink) = self.blink
+        {
+            // Close quickly, then open again
+            let t = blink.as_secs_f32() / Self::BLINK_DURATION.as_secs_f32();
+            elements.close_eyes(1. - (2. * t - 1.).abs());
+        }
+
+        if self.breathing {
+            let offset = roundf(sinf(self.breath) * self.breathing_amplitude as f32) as i32;
+            elements.translate(Point::new(0, offset));
+        }
+    }
+
+    /// Get the current offset of the pupils.
+    #[expect(clippy::cast_precision_loss, reason = "Offsets will never be that large")]
+    #[expect(clippy::cast_possible_truncation, reason = "Offsets will never be that large")]
+    fn gaze(&self) -> Point {
+        let t = self.saccade.as_secs_f32() / Self::SACCADE_DURATION.as_secs_f32();
+        let delta = self.gaze_to - self.gaze_from;
+        self.gaze_from
+            + Point::new(roundf(delta.x as f32 * t) as i32, roundf(delta.y as f32 * t) as i32)
+    }
+}
+
+impl Default for Idle {
+    fn default() -> Self { Self::new(0) }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A small, fast pseudo-random number generator.
+///
+/// Uses the `xorshift32` algorithm, which is good enough for idle movements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rng(u32);
+
+impl Rng {
+    /// Create a new [`Rng`] from a seed.
+    ///
+    /// `xorshift32` gets stuck on zero, so it is replaced with another seed.
+    const fn new(seed: u32) -> Self { Self(if seed == 0 { 0x2545_F491 } else { seed }) }
+
+    /// Get the next random number.
+    const fn next_u32(&mut self) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0
+    }
+
+    /// Get the next random number between `0.0` and `1.0`.
+    #[expect(clippy::cast_precision_loss, reason = "Only the top 24 bits are used")]
+    fn next_f32(&mut self) -> f32 { (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32 }
+
+    /// Get a random duration between `min` and `max`.
+    fn next_duration(&mut self, (min, max): (Duration, Duration)) -> Duration {
+        min + max.saturating_sub(min).mul_f32(self.next_f32())
+    }
+
+    /// Get a random point no further than `distance` from the origin.
+    #[expect(clippy::cast_precision_loss, reason = "Distances will never be that large")]
+    #[expect(clippy::cast_possible_truncation, reason = "Distances will never be that large")]
+    fn next_point(&mut self, distance: u32) -> Point {
+        let angle = self.next_f32() * TAU;
+        let distance = self.next_f32() * distance as f32;
+        Point::new(roundf(cosf(angle) * distance) as i32, roundf(sinf(angle) * distance) as i32)
+    }
+}
@@ -0,0 +1,181 @@
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+use crate::{KerfurTransform, math::roundf};
+
+/// A mapping from Kerfur's canvas onto an area of a display.
+///
+/// All [`KerfurElements`](crate::KerfurElements) are defined on a
+/// [`KerfurLayout::CANVAS`] sized canvas, and are uniformly scaled so that the
+/// [`KerfurLayout::FACE`] fits inside the area.
+///
+/// A [`KerfurTransform`] can be added to move, scale or rotate the face
+/// before it is fit onto the display.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KerfurLayout {
+    scale: f32,
+    center_2x: Point,
+    transform: KerfurTransform,
+}
+
+impl KerfurLayout {
+    /// The size of the canvas facial elements are defined on.
+    pub const CANVAS: Size = Size::new_equal(480);
+    /// The area of the canvas that must always be visible.
+    ///
+    /// The face is wider than it is tall, so this is used instead of the
+    /// whole canvas to make better use of non-square displays.
+    pub const FACE: Rectangle = Rectangle::new(Point::new(0, 96), Size::new(480, 288));
+    /// A layout that draws the canvas as-is.
+    pub const IDENTITY: Self =
+        Self { scale: 1., center_2x: Point::new(480, 480), transform: KerfurTransform::IDENTITY };
+
+    /// Create a [`KerfurLayout`] that fits the face inside the given area.
+    #[must_use]
+    #[expect(clippy::cast_precision_loss, reason = "Sizes will never be that large")]
+    #[expect(clippy::cast_possible_wrap, reason = "Sizes will never be that large")]
+    pub fn new(area: Rectangle) -> Self {
+        let scale_x = area.size.width as f32 / Self::FACE.size.width as f32;
+        let scale_y = area.size.height as f32 / Self::FACE.size.height as f32;
+
+        Self {
+            scale: scale_x.min(scale_y),
+            center_2x: Point::new(
+                area.top_left.x * 2 + area.size.width as i32,
+                area.top_left.y * 2 + area.size.height as i32,
+            ),
+            transform: KerfurTransform::IDENTITY,
+        }
+    }
+
+    /// Create a [`KerfurLayout`] that fits the face on a display of the given
+    /// size.
+    #[inline]
+    #[must_use]
+    pub fn with_size(size: Size) -> Self { Self::new(Rectangle::new(Point::zero(), size)) }
+
+    /// Apply a [`KerfurTransform`] to the face before fitting it inside the
+    /// area.
+    #[inline]
+    #[must_use]
+    pub const fn with_transform(mut self, transform: KerfurTransform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// Get the amount the canvas is scaled by.
+    ///
+    /// This does not include the scale of the [`KerfurTransform`].
+    #[inline]
+    #[must_use]
+    pub const fn scale(&self) -> f32 { self.scale }
+
+    /// Get the [`KerfurTransform`] applied to the face.
+    #[inline]
+    #[must_use]
+    pub const fn transform(&self) -> &KerfurTransform { &self.transform }
+
+    /// Map a point on the canvas onto the display.
+    #[must_use]
+    pub fn point(&self, point: Point) -> Point {
+        self.display_point(self.transform_point(point * 2))
+    }
+
+    /// Map a point on the display back onto the canvas.
+    ///
+    /// This is the inverse of [`KerfurLayout::point`].
+    #[must_use]
+    #[expect(clippy::cast_precision_loss, reason = "Positions will never be that large")]
+    #[expect(clippy::cast_possible_truncation, reason = "Positions will never be that large")]
+    pub fn canvas_point(&self, point: Point) -> Point {
+        let face_2x = Self::FACE.top_left * 2 + Self::FACE.size;
+        let offset_2x = point * 2 - self.center_2x;
+        let translation_2x = self.transform.translation() * 2;
+        let (x, y) = self.transform.inverse_vector((
+            offset_2x.x as f32 / self.scale - translation_2x.x as f32,
+            offset_2x.y as f32 / self.scale - translation_2x.y as f32,
+        ));
+        Point::new(
+            roundf(f32::midpoint(x, face_2x.x as f32)) as i32,
+            roundf(f32::midpoint(y, face_2x.y as f32)) as i32,
+        )
+    }
+
+    /// Map a vector on the canvas onto the display.
+    ///
+    /// Unlike [`KerfurLayout::point`], this does not move the vector.
+    #[must_use]
+    #[expect(clippy::cast_precision_loss, reason = "Positions will never be that large")]
+    #[expect(clippy::cast_possible_truncation, reason = "Positions will never be that large")]
+    pub fn vector(&self, vector: Point) -> Point {
+        let (x, y) = self.transform.vector((vector.x as f32, vector.y as f32));
+        Point::new(roundf(x * self.scale) as i32, roundf(y * self.scale) as i32)
+    }
+
+    /// Map a length on the canvas onto the display.
+    ///
+    /// Non-zero lengths are never scaled down to zero.
+    ///
+    /// Lengths are scaled by the geometric mean of the scales of the
+    /// [`KerfurTransform`].
+    #[must_use]
+    #[expect(clippy::cast_precision_loss, reason = "Lengths will never be that large")]
+    #[expect(clippy::cast_possible_truncation, reason = "Lengths will never be that large")]
+    #[expect(clippy::cast_sign_loss, reason = "Lengths will never be negative")]
+    pub fn length(&self, length: u32) -> u32 {
+        if length == 0 {
+            0
+        } else {
+            let scale = self.scale * self.transform.length_scale();
+            (roundf(length as f32 * scale) as u32).max(1)
+        }
+    }
+
+    /// Map an angle on the canvas onto the display, in radians.
+    #[inline]
+    #[must_use]
+    pub fn angle(&self, angle: f32) -> f32 { angle + self.transform.rotation() }
+
+    /// Map a rectangle on the canvas onto the display.
+    ///
+    /// Rectangles are never rotated, only their center follows the rotation
+    /// of the [`KerfurTransform`].
+    #[must_use]
+    #[expect(clippy::cast_precision_loss, reason = "Sizes will never be that large")]
+    #[expect(clippy::cast_sign_loss, reason = "Sizes will never be negative")]
+    pub fn rectangle(&self, rectangle: Rectangle) -> Rectangle {
+        let (x, y) = self.transform_point(rectangle.top_left * 2 + rectangle.size);
+        let (width, height) = (
+            rectangle.size.width as f32 * self.transform.scale_x(),
+            rectangle.size.height as f32 * self.transform.scale_y(),
+        );
+        let top_left = self.display_point((x - width, y - height));
+        let bottom_right = self.display_point((x + width, y + height));
+        let size = bottom_right - top_left;
+        Rectangle::new(top_left, Size::new(size.x.max(0) as u32, size.y.max(0) as u32))
+    }
+
+    // Apply the transform to twice a point on the canvas,
+    // returning twice its offset from the center of the face.
+    #[expect(clippy::cast_precision_loss, reason = "Positions will never be that large")]
+    fn transform_point(&self, point_2x: Point) -> (f32, f32) {
+        let face_2x = Self::FACE.top_left * 2 + Self::FACE.size;
+        let offset_2x = point_2x - face_2x;
+        let translation_2x = self.transform.translation() * 2;
+        let (x, y) = self.transform.vector((offset_2x.x as f32, offset_2x.y as f32));
+        (x + translation_2x.x as f32, y + translation_2x.y as f32)
+    }
+
+    // Map twice the offset from the center of the face onto the display.
+    #[expect(clippy::cast_precision_loss, reason = "Positions will never be that large")]
+    #[expect(clippy::cast_possible_truncation, reason = "Positions will never be that large")]
+    fn display_point(&self, (x, y): (f32, f32)) -> Point {
+        Point::new(
+            roundf(f32::midpoint(x * self.scale, self.center_2x.x as f32)) as i32,
+            roundf(f32::midpoint(y * self.scale, self.center_2x.y as f32)) as i32,
+        )
+    }
+}
+
+impl Default for KerfurLayout {
+    fn default() -> Self { Self::IDENTITY }
+}
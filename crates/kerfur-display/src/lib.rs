@@ -4,13 +4,22 @@
 #[cfg(feature = "std")]
 extern crate std;
 
-use core::ops::{Deref, DerefMut};
+use core::{
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
 
 use embedded_graphics::{
     pixelcolor::{BinaryColor, Rgb565, Rgb666, Rgb888},
     prelude::*,
 };
 
+mod math;
+
+pub mod animation;
+use animation::SpringState;
+pub use animation::{Easing, Transition};
+
 pub mod primitive;
 
 pub mod element;
@@ -19,19 +28,50 @@ pub use element::KerfurElements;
 mod expression;
 pub use expression::{KerfurEmote, KerfurExpression};
 
+pub mod idle;
+pub use idle::Idle;
+
+mod layout;
+pub use layout::KerfurLayout;
+
 pub mod style;
 pub use style::KerfurStyle;
 
+pub mod timeline;
+pub use timeline::{Keyframe, Repeat, Timeline};
+use timeline::{Playback, Step};
+
+mod transform;
+pub use transform::KerfurTransform;
+
+#[cfg(test)]
+mod tests;
+
 /// A display that draws Kerfur's face
-pub struct KerfurDisplay<'style, D: DrawTargetExt> {
+pub struct KerfurDisplay<'style, 'timeline, D: DrawTargetExt> {
     display: D,
     style: &'style KerfurStyle<D::Color>,
     current: KerfurElements,
     target: KerfurElements,
-    animating: bool,
+    start: KerfurElements,
+    transition: Transition,
+    active: Transition,
+    distance: f32,
+    progress: f32,
+    spring: SpringState,
+    spin: f32,
+    timeline: Option<Playback<'timeline>>,
+    idle: Option<Idle>,
+    gaze: Option<(Point, bool)>,
+    mouth_openness: f32,
+    twitch: Option<Duration>,
+    transform: KerfurTransform,
 }
 
-impl<'style, D: DrawTargetExt> KerfurDisplay<'style, D> {
+impl<'style, 'timeline, D: DrawTargetExt> KerfurDisplay<'style, 'timeline, D> {
+    /// How long a whisker twitch takes.
+    pub const TWITCH_DURATION: Duration = Duration::from_millis(600);
+
     /// Create a new [`KerfurDisplay`].
     #[inline]
     #[must_use]
@@ -50,7 +90,25 @@ impl<'style, D: DrawTargetExt> KerfurDisplay<'style, D> {
         style: &'style KerfurStyle<D::Color>,
         elements: KerfurElements,
     ) -> Self {
-        Self { display, style, current: elements, target: elements, animating: false }
+        Self {
+            display,
+            style,
+            current: elements,
+            target: elements,
+            start: elements,
+            transition: Transition::DEFAULT,
+            active: Transition::DEFAULT,
+            distance: 0.,
+            progress: 1.,
+            spring: SpringState::REST,
+            spin: 0.,
+            timeline: None,
+            idle: None,
+            gaze: None,
+            mouth_openness: 0.,
+            twitch: None,
+            transform: KerfurTransform::IDENTITY,
+        }
     }
 
     /// Set the display style.
@@ -61,6 +119,22 @@ impl<'style, D: DrawTargetExt> KerfurDisplay<'style, D> {
         self
     }
 
+    /// Set the transition used when changing expressions.
+    #[inline]
+    #[must_use]
+    pub const fn with_transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// Set the idle movements layered on top of every expression.
+    #[inline]
+    #[must_use]
+    pub const fn with_idle(mut self, idle: Idle) -> Self {
+        self.idle = Some(idle);
+        self
+    }
+
     /// Get a reference to the inner display.
     #[inline]
     #[must_use]
@@ -74,7 +148,7 @@ impl<'style, D: DrawTargetExt> KerfurDisplay<'style, D> {
     /// Get a reference to the display style.
     #[inline]
     #[must_use]
-    pub const fn style(&self) -> &'style KerfurStyle<D::Color> { &self.style }
+    pub const fn style(&self) -> &'style KerfurStyle<D::Color> { self.style }
 
     /// Get Kerfur's current expression.
     ///
@@ -91,15 +165,129 @@ impl<'style, D: DrawTargetExt> KerfurDisplay<'style, D> {
     #[must_use]
     pub const fn get_expression_target(&self) -> KerfurElements { self.target }
 
+    /// Get the transition used when changing expressions.
+    #[inline]
+    #[must_use]
+    pub const fn transition(&self) -> Transition { self.transition }
+
+    /// Set the transition used when changing expressions.
+    ///
+    /// This does not affect the transition currently being animated.
+    #[inline]
+    pub const fn set_transition(&mut self, transition: Transition) { self.transition = transition; }
+
+    /// Get the idle movements layered on top of every expression.
+    #[inline]
+    #[must_use]
+    pub const fn idle(&self) -> Option<&Idle> { self.idle.as_ref() }
+
+    /// Set the idle movements layered on top of every expression.
+    ///
+    /// Use `None` to disable idle movements.
+    #[inline]
+    pub const fn set_idle(&mut self, idle: Option<Idle>) { self.idle = idle; }
+
+    /// Make Kerfur look at a point on the display.
+    ///
+    /// Both eyes look in the same direction, and keep looking at the point
+    /// when the expression changes.
+    ///
+    /// See [`KerfurElements::look_at`] for more information.
+    #[inline]
+    pub const fn look_at(&mut self, target: Point) { self.gaze = Some((target, false)); }
+
+    /// Make Kerfur look at a nearby point on the display.
+    ///
+    /// Each eye looks at the point separately, and keeps looking at the point
+    /// when the expression changes.
+    ///
+    /// See [`KerfurElements::look_at_converging`] for more information.
+    #[inline]
+    pub const fn look_at_converging(&mut self, target: Point) { self.gaze = Some((target, true)); }
+
+    /// Stop looking at a point, and use the expression's gaze instead.
+    #[inline]
+    pub const fn look_forward(&mut self) { self.gaze = None; }
+
+    /// Get the point on the display Kerfur is looking at, if any.
+    #[inline]
+    #[must_use]
+    pub const fn gaze(&self) -> Option<Point> {
+        match self.gaze {
+            Some((target, _)) => Some(target),
+            None => None,
+        }
+    }
+
+    /// Get how far Kerfur's mouth is opened on top of the expression.
+    #[inline]
+    #[must_use]
+    pub const fn mouth_openness(&self) -> f32 { self.mouth_openness }
+
+    /// Open Kerfur's mouth on top of the current expression.
+    ///
+    /// An `openness` of `0.0` shows the expression's mouth as it is,
+    /// and `1.0` opens it as wide as it goes. The value is clamped between
+    /// `0.0` and `1.0`, and `NaN` is treated as `0.0`.
+    ///
+    /// The mouth stays open when the expression changes,
+    /// so it can follow speech while showing any expression.
+    #[inline]
+    pub fn set_mouth_openness(&mut self, openness: f32) {
+        self.mouth_openness = if openness.is_nan() { 0. } else { openness.clamp(0., 1.) };
+    }
+
+    /// Twitch Kerfur's whiskers, such as when the nose is touched.
+    ///
+    /// The whiskers flick back and forth on top of the current expression,
+    /// then settle back into place after [`KerfurDisplay::TWITCH_DURATION`].
+    /// Twitching again while already twitching restarts the twitch.
+    #[inline]
+    pub const fn twitch_whiskers(&mut self) { self.twitch = Some(Duration::ZERO); }
+
+    /// Get the [`KerfurTransform`] applied to the whole face.
+    #[inline]
+    #[must_use]
+    pub const fn transform(&self) -> &KerfurTransform { &self.transform }
+
+    /// Move, scale or rotate the whole face as it is drawn.
+    ///
+    /// The transform is applied on top of any expression, so the head can be
+    /// tilted, bobbed or squashed by changing it between calls to
+    /// [`KerfurDisplay::draw`].
+    #[inline]
+    pub const fn set_transform(&mut self, transform: KerfurTransform) {
+        self.transform = transform;
+    }
+
     /// Set Kerfur's target expression.
     ///
     /// # Note
     ///
     /// This will not immediately change the expression,
     /// but will animate toward it over time.
+    ///
+    /// This stops any [`Timeline`] that is currently playing.
+    #[inline]
     pub fn set_expression<E: KerfurExpression>(&mut self, expression: E) {
-        self.animating = true;
-        self.target = expression.into_elements();
+        self.set_expression_with(expression, self.transition);
+    }
+
+    /// Set Kerfur's target expression, using the given transition.
+    ///
+    /// # Note
+    ///
+    /// This will not immediately change the expression,
+    /// but will animate toward it over time.
+    ///
+    /// This stops any [`Timeline`] that is currently playing.
+    pub fn set_expression_with<E: KerfurExpression>(
+        &mut self,
+        expression: E,
+        transition: Transition,
+    ) {
+        self.timeline = None;
+        self.restart(&expression.into_elements(), transition);
     }
 
     /// Set Kerfur's current expression.
@@ -109,16 +297,94 @@ impl<'style, D: DrawTargetExt> KerfurDisplay<'style, D> {
     /// This does not change the target expression,
     /// and will continue to animate toward it.
     pub fn set_expression_immediate<E: KerfurExpression>(&mut self, expression: E) {
-        self.animating = true;
         self.current = expression.into_elements();
+        self.spring = SpringState::REST;
+        let target = self.target;
+        self.restart(&target, self.active);
     }
 
-    /// Returns `true` if Kerfur is currently animating between expressions.
+    /// Play a [`Timeline`] of expressions.
+    ///
+    /// Each [`Keyframe`] is animated toward in order as the display is drawn,
+    /// replacing any timeline that is currently playing.
+    pub fn play(&mut self, timeline: Timeline<'timeline>) {
+        self.timeline = Playback::new(timeline);
+        if let Some(playback) = &self.timeline {
+            let keyframe = playback.keyframe();
+            self.restart(&keyframe.elements(), keyframe.transition());
+        }
+    }
+
+    /// Stop playing the current [`Timeline`].
+    ///
+    /// Kerfur will continue animating toward the current keyframe.
+    #[inline]
+    pub fn stop(&mut self) { self.timeline = None; }
+
+    /// Returns `true` if a [`Timeline`] is currently playing.
+    ///
+    /// Timelines that don't repeat stop playing once the last keyframe has
+    /// been reached and held.
+    #[inline]
     #[must_use]
-    pub fn is_animating(&self) -> bool { self.animating }
+    pub const fn is_playing(&self) -> bool { self.timeline.is_some() }
+
+    /// Start animating from the current expression toward a new target.
+    fn restart(&mut self, target: &KerfurElements, transition: Transition) {
+        // Springs that are still moving carry their speed into the new target
+        let spring = match self.active {
+            Transition::Spring(..) if self.progress < 1. => self.spring,
+            _ => SpringState::REST,
+        };
+        self.spring = spring.retarget((&self.start, &self.target), &self.current, target);
+        self.start = self.current;
+        self.target = *target;
+        self.active = transition;
+        self.distance = KerfurElements::distance(&self.start, &self.target);
+        self.progress = 0.;
+    }
+
+    /// Returns `true` if Kerfur is currently animating.
+    ///
+    /// This is `true` while changing between expressions,
+    /// while showing an expression that moves on its own,
+    /// while playing a [`Timeline`], while the whiskers are twitching,
+    /// and while using [`Idle`] movements.
+    #[must_use]
+    pub fn is_animating(&self) -> bool {
+        self.progress < 1.
+            || self.current.eye_spin() != 0.
+            || self.timeline.is_some()
+            || self.twitch.is_some()
+            || self.idle.is_some()
+    }
+
+    /// Get the progress of the current transition.
+    ///
+    /// This is `0.0` when a transition starts and `1.0` once every element
+    /// has reached the target expression, and does not include easing.
+    ///
+    /// With a [`Transition::Spring`], this stays below `1.0` until the
+    /// spring has settled, even while it overshoots the target expression.
+    #[inline]
+    #[must_use]
+    pub const fn progress(&self) -> f32 { self.progress }
+
+    /// Get the layout used to fit Kerfur's face on the display.
+    ///
+    /// This is calculated from the bounding box of the display,
+    /// and includes the [`KerfurTransform`] applied to the face.
+    #[inline]
+    #[must_use]
+    pub fn layout(&self) -> KerfurLayout {
+        KerfurLayout::new(self.display.bounding_box()).with_transform(self.transform)
+    }
 
     /// Animate the display and draw the face
     ///
+    /// The `elapsed` time is the time since the last call,
+    /// and is used to advance any animations.
+    ///
     /// # Warning
     ///
     /// This method does not flush the display!
@@ -126,30 +392,155 @@ impl<'style, D: DrawTargetExt> KerfurDisplay<'style, D> {
     /// # Errors
     ///
     /// Returns an error if drawing to the display fails.
-    pub fn draw(&mut self, tick: f32) -> Result<(), D::Error> {
-        if self.animating {
-            self.current.interpolate(&self.target, tick);
-            self.animating = self.current != self.target;
+    pub fn draw(&mut self, elapsed: Duration) -> Result<(), D::Error> {
+        self.advance(elapsed);
+
+        let mut elements = self.current;
+        let spin = elements.eye_spin();
+        if spin != 0. {
+            self.spin = math::wrap_angle(self.spin + spin * elapsed.as_secs_f32());
+            elements.rotate_swirls(self.spin);
+        }
+
+        let layout = self.layout();
+        if let Some((target, converge)) = self.gaze {
+            let target = layout.canvas_point(target);
+            elements = if converge {
+                elements.look_at_converging(target)
+            } else {
+                elements.look_at(target)
+            };
+        }
+
+        if self.mouth_openness > 0. {
+            elements.open_mouth(self.mouth_openness);
+        }
+
+        if let Some(twitch) = self.twitch {
+            let twitch = twitch + elapsed;
+            self.twitch = (twitch < Self::TWITCH_DURATION).then_some(twitch);
+            elements.twitch_whiskers(twitch.as_secs_f32() / Self::TWITCH_DURATION.as_secs_f32());
+        }
+
+        if let Some(idle) = &mut self.idle {
+            idle.update(elapsed);
+            idle.apply(&mut elements);
+        }
+
+        if layout == KerfurLayout::IDENTITY {
+            elements.draw(&mut self.display, self.style)
+        } else {
+            let style = self.style.with_layout(&layout);
+            elements.with_layout(&layout).draw(&mut self.display, &style)
+        }
+    }
+
+    /// Advance the current transition and timeline.
+    ///
+    /// Time left over after a transition or hold finishes is carried into
+    /// the next one, so timelines keep time regardless of the frame rate.
+    fn advance(&mut self, mut elapsed: Duration) {
+        // Play at most one keyframe per frame for each keyframe in the timeline,
+        // timelines without any transitions or holds would never take any time
+        let keyframes = self.timeline.map_or(0, |playback| playback.len());
+        for _ in 0..=keyframes {
+            if self.progress < 1. {
+                elapsed = self.animate(elapsed);
+                if self.progress < 1. {
+                    return;
+                }
+            }
+
+            let Some(playback) = &mut self.timeline else { return };
+            match playback.hold(elapsed) {
+                Step::Hold => return,
+                Step::Next(remaining) => {
+                    let keyframe = playback.keyframe();
+                    self.restart(&keyframe.elements(), keyframe.transition());
+                    elapsed = remaining;
+                }
+                Step::Finished => {
+                    self.timeline = None;
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Advance the current transition.
+    ///
+    /// Returns the time left over after the transition finished.
+    fn animate(&mut self, elapsed: Duration) -> Duration {
+        let (step, easing) = match self.active {
+            // Move the furthest element at the given speed
+            Transition::Speed(speed) if self.distance > 0. => {
+                (speed * elapsed.as_secs_f32() / self.distance, Easing::Linear)
+            }
+            Transition::Timed(duration, easing) if !duration.is_zero() => {
+                (elapsed.as_secs_f32() / duration.as_secs_f32(), easing)
+            }
+            Transition::Spring(stiffness, damping) if self.distance > 0. && stiffness > 0. => {
+                return self.animate_spring(stiffness, damping, elapsed);
+            }
+            _ => {
+                self.progress = 1.;
+                self.current = self.target;
+                return elapsed;
+            }
+        };
+
+        let progress = self.progress + step;
+        if progress >= 1. {
+            self.progress = 1.;
+            self.current = self.target;
+            // The fraction of the elapsed time after the transition finished
+            let remaining = (progress - 1.) / step;
+            if remaining.is_finite() {
+                elapsed.mul_f32(remaining.clamp(0., 1.))
+            } else {
+                Duration::ZERO
+            }
+        } else {
+            self.progress = progress;
+            let progress = easing.apply(progress);
+            self.current = KerfurElements::interpolate(&self.start, &self.target, progress);
+            Duration::ZERO
+        }
+    }
+
+    /// Pull each element toward the target expression with its own spring,
+    /// which may overshoot the target before settling.
+    ///
+    /// Returns the time left over after the springs settled.
+    fn animate_spring(&mut self, stiffness: f32, damping: f32, elapsed: Duration) -> Duration {
+        if let Some(used) = self.spring.step(stiffness, damping, elapsed.as_secs_f32()) {
+            self.progress = 1.;
+            self.current = self.target;
+            elapsed.saturating_sub(Duration::from_secs_f32(used))
+        } else {
+            self.progress = self.spring.progress();
+            let positions = self.spring.positions();
+            self.current = KerfurElements::interpolate_parts(&self.start, &self.target, positions);
+            Duration::ZERO
         }
-        self.current.draw(&mut self.display, &self.style)
     }
 }
 
-impl<D: DrawTargetExt> Deref for KerfurDisplay<'_, D> {
+impl<D: DrawTargetExt> Deref for KerfurDisplay<'_, '_, D> {
     type Target = D;
 
     #[inline]
     fn deref(&self) -> &Self::Target { &self.display }
 }
 
-impl<D: DrawTargetExt> DerefMut for KerfurDisplay<'_, D> {
+impl<D: DrawTargetExt> DerefMut for KerfurDisplay<'_, '_, D> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target { &mut self.display }
 }
 
 // -------------------------------------------------------------------------------------------------
 
-impl<D: DrawTargetExt<Color = Rgb565>> KerfurDisplay<'static, D> {
+impl<D: DrawTargetExt<Color = Rgb565>> KerfurDisplay<'static, '_, D> {
     /// Create a new blue [`KerfurDisplay`].
     ///
     /// Uses the [`BLUE`](crate::style::BLUE) style.
@@ -175,7 +566,7 @@ impl<D: DrawTargetExt<Color = Rgb565>> KerfurDisplay<'static, D> {
     }
 }
 
-impl<D: DrawTargetExt<Color = Rgb666>> KerfurDisplay<'static, D> {
+impl<D: DrawTargetExt<Color = Rgb666>> KerfurDisplay<'static, '_, D> {
     /// Create a new blue [`KerfurDisplay`].
     ///
     /// Uses the [`BLUE`](crate::style::BLUE) style.
@@ -201,7 +592,7 @@ impl<D: DrawTargetExt<Color = Rgb666>> KerfurDisplay<'static, D> {
     }
 }
 
-impl<D: DrawTargetExt<Color = Rgb888>> KerfurDisplay<'static, D> {
+impl<D: DrawTargetExt<Color = Rgb888>> KerfurDisplay<'static, '_, D> {
     /// Create a new blue [`KerfurDisplay`].
     ///
     /// Uses the [`BLUE`](crate::style::BLUE) style.
@@ -227,7 +618,7 @@ impl<D: DrawTargetExt<Color = Rgb888>> KerfurDisplay<'static, D> {
     }
 }
 
-impl<D: DrawTargetExt<Color = BinaryColor>> KerfurDisplay<'static, D> {
+impl<D: DrawTargetExt<Color = BinaryColor>> KerfurDisplay<'static, '_, D> {
     /// Create a new binary [`KerfurDisplay`].
     ///
     /// Uses the [`BINARY_ON`](crate::style::BINARY_ON) style.
@@ -0,0 +1,119 @@
+//! Floating-point helpers that work with either `std` or `libm`.
+
+use core::f32::consts::{PI, TAU};
+
+#[inline]
+#[must_use]
+pub(crate) fn sqrtf(x: f32) -> f32 {
+    #[cfg(feature = "libm")]
+    {
+        libm::sqrtf(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.sqrt()
+    }
+}
+
+#[inline]
+#[must_use]
+pub(crate) fn atan2f(y: f32, x: f32) -> f32 {
+    #[cfg(feature = "libm")]
+    {
+        libm::atan2f(y, x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        y.atan2(x)
+    }
+}
+
+#[inline]
+#[must_use]
+pub(crate) fn ceilf(x: f32) -> f32 {
+    #[cfg(feature = "libm")]
+    {
+        libm::ceilf(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.ceil()
+    }
+}
+
+#[inline]
+#[must_use]
+pub(crate) fn floorf(x: f32) -> f32 {
+    #[cfg(feature = "libm")]
+    {
+        libm::floorf(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.floor()
+    }
+}
+
+#[inline]
+#[must_use]
+pub(crate) fn sinf(x: f32) -> f32 {
+    #[cfg(feature = "libm")]
+    {
+        libm::sinf(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.sin()
+    }
+}
+
+#[inline]
+#[must_use]
+pub(crate) fn cosf(x: f32) -> f32 {
+    #[cfg(feature = "libm")]
+    {
+        libm::cosf(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.cos()
+    }
+}
+
+#[inline]
+#[must_use]
+pub(crate) fn roundf(x: f32) -> f32 {
+    #[cfg(feature = "libm")]
+    {
+        libm::roundf(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.round()
+    }
+}
+
+#[inline]
+#[must_use]
+pub(crate) fn exp2f(x: f32) -> f32 {
+    #[cfg(feature = "libm")]
+    {
+        libm::exp2f(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.exp2()
+    }
+}
+
+/// Wrap an angle into the range `[0, 2π)`.
+#[inline]
+#[must_use]
+pub(crate) fn wrap_angle(angle: f32) -> f32 { angle - floorf(angle / TAU) * TAU }
+
+/// Get the shortest signed rotation from angle `a` to angle `b`.
+///
+/// The result is in the range `[-π, π)`.
+#[inline]
+#[must_use]
+pub(crate) fn angle_between(a: f32, b: f32) -> f32 { wrap_angle(b - a + PI) - PI }
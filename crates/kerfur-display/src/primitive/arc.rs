@@ -1,5 +1,5 @@
 use embedded_graphics::{
-    prelude::{Angle, Point},
+    prelude::{Angle, Point, Size},
     primitives::{Arc, Circle},
 };
 
@@ -22,12 +22,14 @@ pub struct ConstArc {
 impl ConstArc {
     /// Create a new arc delimited with a top-left point with a specific
     /// diameter and start and sweep angles
+    #[must_use]
     pub const fn new(top_left: Point, diameter: u32, angle_start: f32, angle_sweep: f32) -> Self {
         ConstArc { top_left, diameter, angle_start, angle_sweep }
     }
 
     /// Create a new arc centered around a given point with a specific diameter
     /// and start and sweep angles
+    #[must_use]
     pub const fn with_center(
         center: Point,
         diameter: u32,
@@ -41,10 +43,32 @@ impl ConstArc {
     ///
     /// The resulting arc will match the `top_left` and `diameter` of the base
     /// circle.
+    #[must_use]
     pub const fn from_circle(circle: Circle, angle_start: f32, angle_sweep: f32) -> Self {
         Self { top_left: circle.top_left, diameter: circle.diameter, angle_start, angle_sweep }
     }
 
+    /// Get the center point of the circle the arc is drawn on.
+    #[must_use]
+    pub const fn center(&self) -> Point {
+        let radius = (self.diameter.saturating_sub(1) / 2).cast_signed();
+        Point::new(self.top_left.x + radius, self.top_left.y + radius)
+    }
+
+    /// Mirror the arc across the face's vertical center line.
+    ///
+    /// The sweep keeps its direction, so an arc on the center line mirrors
+    /// onto itself and arcs can be interpolated with their mirror images.
+    #[must_use]
+    pub const fn mirrored(self) -> Self {
+        Self {
+            top_left: super::mirror_top_left(self.top_left, Size::new_equal(self.diameter)),
+            diameter: self.diameter,
+            angle_start: super::mirror_angle(self.angle_start + self.angle_sweep),
+            angle_sweep: self.angle_sweep,
+        }
+    }
+
     /// Convert this [`ConstArc`] into an [`Arc`].
     #[must_use]
     pub fn into_arc(self) -> Arc {
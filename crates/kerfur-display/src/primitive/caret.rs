@@ -0,0 +1,92 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Polyline, PrimitiveStyle, Rectangle, StyledDrawable},
+};
+
+/// Caret primitive
+///
+/// Two lines meeting at the middle of the top edge of the bounding box,
+/// like the eyes of a `^_^` face.
+///
+/// Like a [`Line`](embedded_graphics::primitives::Line), a caret only has a
+/// stroke, and any fill color in the [`PrimitiveStyle`] is ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Caret {
+    /// The bounding box of the caret
+    pub rectangle: Rectangle,
+}
+
+impl Caret {
+    /// Create a new [`Caret`].
+    #[must_use]
+    pub const fn new(top_left: Point, size: Size) -> Self {
+        Self { rectangle: Rectangle::new(top_left, size) }
+    }
+
+    /// Create a new [`Caret`] with the given center point.
+    #[must_use]
+    pub const fn with_center(center: Point, size: Size) -> Self {
+        Self { rectangle: Rectangle::with_center(center, size) }
+    }
+
+    /// Mirror the caret across the face's vertical center line.
+    ///
+    /// A caret is symmetric, so only its position is mirrored.
+    #[must_use]
+    pub const fn mirrored(self) -> Self {
+        let size = self.rectangle.size;
+        Self::new(super::mirror_top_left(self.rectangle.top_left, size), size)
+    }
+
+    /// Get the left end, the tip, and the right end of the caret.
+    #[must_use]
+    pub fn vertices(&self) -> [Point; 3] {
+        let Rectangle { top_left, size } = self.rectangle;
+        let bottom_right = top_left + size.saturating_sub(Size::new(1, 1));
+        [
+            Point::new(top_left.x, bottom_right.y),
+            Point::new(i32::midpoint(top_left.x, bottom_right.x), top_left.y),
+            bottom_right,
+        ]
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl Primitive for Caret {}
+
+impl Dimensions for Caret {
+    #[inline]
+    fn bounding_box(&self) -> Rectangle { self.rectangle }
+}
+
+impl Transform for Caret {
+    fn translate(&self, by: Point) -> Self { Self { rectangle: self.rectangle.translate(by) } }
+
+    #[inline]
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.rectangle.translate_mut(by);
+        self
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl<S: PixelColor> StyledDrawable<PrimitiveStyle<S>> for Caret {
+    type Color = S;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<S>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        if self.rectangle.is_zero_sized() {
+            return Ok(());
+        }
+        Polyline::new(&self.vertices()).draw_styled(style, target)
+    }
+}
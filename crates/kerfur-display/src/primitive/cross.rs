@@ -0,0 +1,97 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Line, PrimitiveStyle, Rectangle, StyledDrawable},
+};
+
+/// Cross primitive
+///
+/// Two lines joining the opposite corners of the bounding box,
+/// like the eyes of a knocked-out face.
+///
+/// Like a [`Line`], a cross only has a stroke,
+/// and any fill color in the [`PrimitiveStyle`] is ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cross {
+    /// The bounding box of the cross
+    pub rectangle: Rectangle,
+}
+
+impl Cross {
+    /// Create a new [`Cross`].
+    #[must_use]
+    pub const fn new(top_left: Point, size: Size) -> Self {
+        Self { rectangle: Rectangle::new(top_left, size) }
+    }
+
+    /// Create a new [`Cross`] with the given center point.
+    #[must_use]
+    pub const fn with_center(center: Point, size: Size) -> Self {
+        Self { rectangle: Rectangle::with_center(center, size) }
+    }
+
+    /// Mirror the cross across the face's vertical center line.
+    ///
+    /// A cross is symmetric, so only its position is mirrored.
+    #[must_use]
+    pub const fn mirrored(self) -> Self {
+        let size = self.rectangle.size;
+        Self::new(super::mirror_top_left(self.rectangle.top_left, size), size)
+    }
+
+    /// Get the two lines that make up the cross.
+    #[must_use]
+    pub fn lines(&self) -> [Line; 2] {
+        let Rectangle { top_left, size } = self.rectangle;
+        let bottom_right = top_left + size.saturating_sub(Size::new(1, 1));
+        [
+            Line::new(top_left, bottom_right),
+            Line::new(
+                Point::new(bottom_right.x, top_left.y),
+                Point::new(top_left.x, bottom_right.y),
+            ),
+        ]
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl Primitive for Cross {}
+
+impl Dimensions for Cross {
+    #[inline]
+    fn bounding_box(&self) -> Rectangle { self.rectangle }
+}
+
+impl Transform for Cross {
+    fn translate(&self, by: Point) -> Self { Self { rectangle: self.rectangle.translate(by) } }
+
+    #[inline]
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.rectangle.translate_mut(by);
+        self
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl<S: PixelColor> StyledDrawable<PrimitiveStyle<S>> for Cross {
+    type Color = S;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<S>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        if self.rectangle.is_zero_sized() {
+            return Ok(());
+        }
+        for line in self.lines() {
+            line.draw_styled(style, target)?;
+        }
+        Ok(())
+    }
+}
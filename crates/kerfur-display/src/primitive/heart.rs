@@ -0,0 +1,105 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{ContainsPoint, PrimitiveStyle, Rectangle, StyledDrawable},
+};
+
+/// Heart primitive
+///
+/// Two round lobes across the top of the bounding box,
+/// narrowing to a point at the middle of the bottom edge.
+///
+/// The stroke of a heart is drawn inside its bounding box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Heart {
+    /// The bounding box of the heart
+    pub rectangle: Rectangle,
+}
+
+impl Heart {
+    /// Create a new [`Heart`].
+    #[must_use]
+    pub const fn new(top_left: Point, size: Size) -> Self {
+        Self { rectangle: Rectangle::new(top_left, size) }
+    }
+
+    /// Create a new [`Heart`] with the given center point.
+    #[must_use]
+    pub const fn with_center(center: Point, size: Size) -> Self {
+        Self { rectangle: Rectangle::with_center(center, size) }
+    }
+
+    /// Mirror the heart across the face's vertical center line.
+    ///
+    /// A heart is symmetric, so only its position is mirrored.
+    #[must_use]
+    pub const fn mirrored(self) -> Self {
+        let size = self.rectangle.size;
+        Self::new(super::mirror_top_left(self.rectangle.top_left, size), size)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl Primitive for Heart {}
+
+impl ContainsPoint for Heart {
+    #[expect(clippy::cast_precision_loss, reason = "Positions will never be that large")]
+    fn contains(&self, point: Point) -> bool {
+        let Rectangle { top_left, size } = self.rectangle;
+        if size.width == 0 || size.height == 0 {
+            return false;
+        }
+
+        // Measure from the top left corner, where the bounding box is `1.0` wide and
+        // tall
+        let x = ((point.x - top_left.x) as f32 + 0.5) / size.width as f32;
+        let y = ((point.y - top_left.y) as f32 + 0.5) / size.height as f32;
+        if !(0. ..=1.).contains(&x) || !(0. ..=1.).contains(&y) {
+            return false;
+        }
+
+        // A lobe on each side of the top, and a triangle below them
+        let lobe = |center: f32| {
+            let (x, y) = (x - center, y - LOBE_RADIUS);
+            x * x + y * y <= LOBE_RADIUS * LOBE_RADIUS
+        };
+        let below = y >= LOBE_RADIUS && (x - 0.5).abs() <= 0.5 * (1. - y) / (1. - LOBE_RADIUS);
+        lobe(LOBE_RADIUS) || lobe(1. - LOBE_RADIUS) || below
+    }
+}
+
+/// The radius of each lobe, relative to the size of the heart.
+const LOBE_RADIUS: f32 = 0.25;
+
+impl Dimensions for Heart {
+    #[inline]
+    fn bounding_box(&self) -> Rectangle { self.rectangle }
+}
+
+impl Transform for Heart {
+    fn translate(&self, by: Point) -> Self { Self { rectangle: self.rectangle.translate(by) } }
+
+    #[inline]
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.rectangle.translate_mut(by);
+        self
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl<S: PixelColor> StyledDrawable<PrimitiveStyle<S>> for Heart {
+    type Color = S;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<S>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        super::draw_region(&self.rectangle, |point| self.contains(point), style, target)
+    }
+}
@@ -0,0 +1,45 @@
+use core::f32::consts::PI;
+
+use embedded_graphics::{
+    prelude::{Point, Size},
+    primitives::{Ellipse, Line},
+};
+
+use crate::KerfurLayout;
+
+/// The x coordinate of the face's vertical center line.
+///
+/// Primitives are mirrored across this line, so an expression can define one
+/// side of the face and mirror it to get the other side. The line runs
+/// between the two middle pixel columns, so pixel `x` is mirrored onto pixel
+/// `2 * MIRROR_X - 1 - x`.
+pub const MIRROR_X: i32 = KerfurLayout::CANVAS.width.cast_signed() / 2;
+
+/// Mirror a point across the face's vertical center line.
+#[must_use]
+pub const fn mirror_point(point: Point) -> Point { Point::new(2 * MIRROR_X - 1 - point.x, point.y) }
+
+/// Mirror an angle horizontally, so it points toward the other side of the
+/// face.
+#[must_use]
+pub const fn mirror_angle(angle: f32) -> f32 { PI - angle }
+
+/// Mirror a [`Line`] across the face's vertical center line.
+#[must_use]
+pub const fn mirror_line(line: Line) -> Line {
+    Line::new(mirror_point(line.start), mirror_point(line.end))
+}
+
+/// Mirror an [`Ellipse`] across the face's vertical center line.
+///
+/// The ellipse keeps its size, and covers the mirror image of its pixels.
+#[must_use]
+pub const fn mirror_ellipse(ellipse: Ellipse) -> Ellipse {
+    Ellipse::new(mirror_top_left(ellipse.top_left, ellipse.size), ellipse.size)
+}
+
+/// Mirror the top-left point of a shape, so it covers the mirror image of
+/// its pixels.
+pub(crate) const fn mirror_top_left(top_left: Point, size: Size) -> Point {
+    Point::new(2 * MIRROR_X - top_left.x - size.width.cast_signed(), top_left.y)
+}
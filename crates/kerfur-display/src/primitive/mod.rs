@@ -3,8 +3,30 @@
 mod arc;
 pub use arc::ConstArc;
 
+mod caret;
+pub use caret::Caret;
+
+mod cross;
+pub use cross::Cross;
+
+mod heart;
+pub use heart::Heart;
+
+mod mirror;
+pub(crate) use mirror::mirror_top_left;
+pub use mirror::{MIRROR_X, mirror_angle, mirror_ellipse, mirror_line, mirror_point};
+
 mod swirl;
-pub use swirl::Swirl;
+pub use swirl::{Swirl, SwirlPointIter};
+
+mod region;
+use region::draw_region;
 
 mod sector;
 pub use sector::ConstSector;
+
+mod star;
+pub use star::Star;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,31 @@
+use embedded_graphics::{
+    Pixel,
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+};
+
+/// Draw a shape by testing every point in its bounding box.
+///
+/// Points inside the shape are filled, and points within the stroke width of
+/// its edge are stroked, so the stroke is always drawn inside the shape.
+pub(super) fn draw_region<D, F>(
+    bounds: &Rectangle,
+    contains: F,
+    style: &PrimitiveStyle<D::Color>,
+    target: &mut D,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget,
+    F: Fn(Point) -> bool,
+{
+    let width = style.stroke_width.cast_signed();
+    let stroke = style.stroke_color.filter(|_| width > 0);
+    let offsets =
+        [Point::new(width, 0), Point::new(-width, 0), Point::new(0, width), Point::new(0, -width)];
+
+    target.draw_iter(bounds.points().filter(|point| contains(*point)).filter_map(|point| {
+        let edge = stroke.is_some() && offsets.iter().any(|offset| !contains(point + *offset));
+        let color = if edge { stroke } else { style.fill_color };
+        color.map(|color| Pixel(point, color))
+    }))
+}
@@ -41,6 +41,27 @@ impl ConstSector {
         ConstSector { top_left, diameter, angle_start, angle_sweep }
     }
 
+    /// Get the center point of the circle the sector is drawn on.
+    #[must_use]
+    pub const fn center(&self) -> Point {
+        let radius = (self.diameter.saturating_sub(1) / 2).cast_signed();
+        Point::new(self.top_left.x + radius, self.top_left.y + radius)
+    }
+
+    /// Mirror the sector across the face's vertical center line.
+    ///
+    /// The sweep keeps its direction, so a sector on the center line mirrors
+    /// onto itself and sectors can be interpolated with their mirror images.
+    #[must_use]
+    pub const fn mirrored(self) -> Self {
+        Self {
+            top_left: super::mirror_top_left(self.top_left, Size::new_equal(self.diameter)),
+            diameter: self.diameter,
+            angle_start: super::mirror_angle(self.angle_start + self.angle_sweep),
+            angle_sweep: self.angle_sweep,
+        }
+    }
+
     /// Convert this [`ConstSector`] into a [`Sector`].
     #[must_use]
     pub fn into_sector(self) -> Sector {
@@ -0,0 +1,119 @@
+use core::f32::consts::{FRAC_PI_2, PI};
+
+use embedded_graphics::{
+    prelude::*,
+    primitives::{ContainsPoint, PrimitiveStyle, Rectangle, StyledDrawable},
+};
+
+use crate::math::{atan2f, cosf, floorf, sinf, sqrtf};
+
+/// Star primitive
+///
+/// A star with [`Star::POINTS`] points, with one point at the middle of
+/// the top edge of the bounding box.
+///
+/// The stroke of a star is drawn inside its bounding box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Star {
+    /// The bounding box of the star
+    pub rectangle: Rectangle,
+}
+
+impl Star {
+    /// The distance of the inner corners from the center,
+    /// relative to the distance of the points.
+    pub const INNER_RADIUS: f32 = 0.45;
+    /// The number of points the star has.
+    pub const POINTS: u32 = 5;
+
+    /// Create a new [`Star`].
+    #[must_use]
+    pub const fn new(top_left: Point, size: Size) -> Self {
+        Self { rectangle: Rectangle::new(top_left, size) }
+    }
+
+    /// Create a new [`Star`] with the given center point.
+    #[must_use]
+    pub const fn with_center(center: Point, size: Size) -> Self {
+        Self { rectangle: Rectangle::with_center(center, size) }
+    }
+
+    /// Mirror the star across the face's vertical center line.
+    ///
+    /// A star is symmetric, so only its position is mirrored.
+    #[must_use]
+    pub const fn mirrored(self) -> Self {
+        let size = self.rectangle.size;
+        Self::new(super::mirror_top_left(self.rectangle.top_left, size), size)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl Primitive for Star {}
+
+impl ContainsPoint for Star {
+    #[expect(clippy::cast_precision_loss, reason = "Positions will never be that large")]
+    fn contains(&self, point: Point) -> bool {
+        let Rectangle { top_left, size } = self.rectangle;
+        if size.width == 0 || size.height == 0 {
+            return false;
+        }
+
+        // Measure from the center, where the points are `1.0` away
+        let (half_width, half_height) = (size.width as f32 / 2., size.height as f32 / 2.);
+        let x = ((point.x - top_left.x) as f32 + 0.5 - half_width) / half_width;
+        let y = ((point.y - top_left.y) as f32 + 0.5 - half_height) / half_height;
+        let radius = sqrtf(x * x + y * y);
+        if radius > 1. {
+            return false;
+        }
+
+        // Fold the angle onto the edge between a point and the next inner corner
+        let half = PI / Self::POINTS as f32;
+        let mut theta = atan2f(y, x) + FRAC_PI_2;
+        theta -= floorf(theta / (2. * half)) * 2. * half;
+        if theta > half {
+            theta = 2. * half - theta;
+        }
+
+        // Stay on the same side of the edge as the center
+        let (corner_x, corner_y) =
+            (Self::INNER_RADIUS * cosf(half), Self::INNER_RADIUS * sinf(half));
+        let (x, y) = (radius * cosf(theta), radius * sinf(theta));
+        (corner_x - 1.) * y - corner_y * (x - 1.) >= 0.
+    }
+}
+
+impl Dimensions for Star {
+    #[inline]
+    fn bounding_box(&self) -> Rectangle { self.rectangle }
+}
+
+impl Transform for Star {
+    fn translate(&self, by: Point) -> Self { Self { rectangle: self.rectangle.translate(by) } }
+
+    #[inline]
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.rectangle.translate_mut(by);
+        self
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl<S: PixelColor> StyledDrawable<PrimitiveStyle<S>> for Star {
+    type Color = S;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<S>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        super::draw_region(&self.rectangle, |point| self.contains(point), style, target)
+    }
+}
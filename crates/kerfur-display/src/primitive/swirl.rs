@@ -1,18 +1,33 @@
+use core::f32::consts::TAU;
+
 use embedded_graphics::{
     prelude::*,
-    primitives::{Circle, PrimitiveStyle, Rectangle, StyledDrawable},
+    primitives::{Circle, PrimitiveStyle, Rectangle, StyledDrawable, rectangle},
 };
 
+use crate::math::{atan2f, ceilf, cosf, floorf, sinf, sqrtf};
+
 /// Swirl primitive
+///
+/// An Archimedean spiral that starts at the center of the circle
+/// and ends on its edge after [`Swirl::TURNS`] revolutions.
+///
+/// Like a [`Line`](embedded_graphics::primitives::Line), a swirl only has a
+/// stroke, and any fill color in the [`PrimitiveStyle`] is ignored.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Swirl {
     /// The angle of the swirl
+    ///
+    /// The direction, in radians, the swirl leaves the center in.
     pub angle: f32,
     /// The circle to draw the swirl in
     pub circle: Circle,
 }
 
 impl Swirl {
+    /// The number of revolutions the swirl makes.
+    pub const TURNS: f32 = 3.0;
+
     /// Create a new [`Swirl`].
     #[must_use]
     pub const fn new(top_left: Point, angle: f32, diameter: u32) -> Self {
@@ -24,6 +39,17 @@ impl Swirl {
     pub const fn with_center(center: Point, angle: f32, diameter: u32) -> Self {
         Self { angle, circle: Circle::with_center(center, diameter) }
     }
+
+    /// Mirror the swirl across the face's vertical center line.
+    ///
+    /// Only the position and angle of the swirl are mirrored,
+    /// a swirl always turns in the same direction.
+    #[must_use]
+    pub const fn mirrored(self) -> Self {
+        let top_left =
+            super::mirror_top_left(self.circle.top_left, Size::new_equal(self.circle.diameter));
+        Self::new(top_left, super::mirror_angle(self.angle), self.circle.diameter)
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -54,18 +80,102 @@ impl Transform for Swirl {
 
 // -------------------------------------------------------------------------------------------------
 
-pub struct SwirlPointIter {}
+/// An iterator over all points on a [`Swirl`].
+///
+/// Points are yielded in row-major order, and each point is yielded once.
+#[derive(Clone)]
+pub struct SwirlPointIter {
+    points: rectangle::Points,
+    center: (f32, f32),
+    angle: f32,
+    spacing: f32,
+    end: (f32, f32),
+    half_width: f32,
+}
+
+impl SwirlPointIter {
+    /// Create a new [`SwirlPointIter`] for a swirl drawn with a given width.
+    #[must_use]
+    #[expect(clippy::cast_precision_loss, reason = "Positions will never be that large")]
+    #[expect(clippy::cast_possible_truncation, reason = "Widths will never be that large")]
+    fn new(swirl: &Swirl, width: u32) -> Self {
+        let Swirl { angle, circle } = *swirl;
+        let half_width = width as f32 / 2.;
+
+        // The stroke extends past the edge of the circle by up to half its width.
+        let points = if width == 0 {
+            Rectangle::zero().points()
+        } else {
+            circle.bounding_box().offset(ceilf(half_width) as i32).points()
+        };
+
+        let radius = circle.diameter as f32 / 2.;
+        let center = (
+            circle.top_left.x as f32 + (circle.diameter as f32 - 1.) / 2.,
+            circle.top_left.y as f32 + (circle.diameter as f32 - 1.) / 2.,
+        );
+
+        // The distance between each revolution of the swirl.
+        let spacing = radius / Swirl::TURNS;
+        // The point the swirl ends on, at the edge of the circle.
+        let end_angle = angle + TAU * Swirl::TURNS;
+        let end = (center.0 + radius * cosf(end_angle), center.1 + radius * sinf(end_angle));
+
+        Self { points, center, angle, spacing, end, half_width }
+    }
+
+    /// Returns `true` if the point is within the stroke of the swirl.
+    #[expect(clippy::cast_precision_loss, reason = "Positions will never be that large")]
+    fn is_on_swirl(&self, point: Point) -> bool {
+        let (x, y) = (point.x as f32 - self.center.0, point.y as f32 - self.center.1);
+        let radius = sqrtf(x * x + y * y);
+
+        // Round caps at the start and end of the swirl.
+        if radius <= self.half_width {
+            return true;
+        }
+        let (end_x, end_y) = (point.x as f32 - self.end.0, point.y as f32 - self.end.1);
+        if sqrtf(end_x * end_x + end_y * end_y) <= self.half_width {
+            return true;
+        }
+
+        // Find the angle of the point relative to the start of the swirl,
+        // then check each revolution of the swirl at that angle.
+        let mut theta = atan2f(y, x) - self.angle;
+        theta -= floorf(theta / TAU) * TAU;
+
+        let end = TAU * Swirl::TURNS;
+        while theta <= end {
+            let distance = radius - theta / TAU * self.spacing;
+            if distance.abs() <= self.half_width {
+                return true;
+            } else if distance < 0. {
+                return false;
+            }
+            theta += TAU;
+        }
+
+        false
+    }
+}
 
 impl PointsIter for Swirl {
     type Iter = SwirlPointIter;
 
-    fn points(&self) -> Self::Iter { todo!() }
+    fn points(&self) -> Self::Iter { SwirlPointIter::new(self, 1) }
 }
 
 impl Iterator for SwirlPointIter {
     type Item = Point;
 
-    fn next(&mut self) -> Option<Self::Item> { todo!() }
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let point = self.points.next()?;
+            if self.is_on_swirl(point) {
+                return Some(point);
+            }
+        }
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -76,12 +186,17 @@ impl<S: PixelColor> StyledDrawable<PrimitiveStyle<S>> for Swirl {
 
     fn draw_styled<D>(
         &self,
-        _style: &PrimitiveStyle<S>,
-        _target: &mut D,
+        style: &PrimitiveStyle<S>,
+        target: &mut D,
     ) -> Result<Self::Output, D::Error>
     where
         D: DrawTarget<Color = Self::Color>,
     {
-        todo!()
+        match style.stroke_color {
+            Some(color) if style.stroke_width > 0 => target.draw_iter(
+                SwirlPointIter::new(self, style.stroke_width).map(|point| Pixel(point, color)),
+            ),
+            _ => Ok(()),
+        }
     }
 }
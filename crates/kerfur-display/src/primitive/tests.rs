@@ -0,0 +1,68 @@
+use core::f32::consts::FRAC_PI_3;
+
+use embedded_graphics::{
+    mock_display::MockDisplay,
+    pixelcolor::BinaryColor,
+    prelude::*,
+    primitives::{PrimitiveStyle, StyledDrawable},
+};
+
+use super::Swirl;
+
+const SWIRLS: [Swirl; 3] = [
+    Swirl::new(Point::new(8, 8), 0., 48),
+    Swirl::new(Point::new(12, 10), FRAC_PI_3, 37),
+    Swirl::new(Point::new(20, 20), -2., 16),
+];
+
+#[test]
+fn swirl_draws_inside_bounds() {
+    for swirl in SWIRLS {
+        for width in [1, 3, 5] {
+            let mut display = MockDisplay::<BinaryColor>::new();
+            swirl
+                .draw_styled(&PrimitiveStyle::with_stroke(BinaryColor::On, width), &mut display)
+                .unwrap();
+
+            // Only the stroke extends past the circle, by up to half its width
+            let bounds = swirl.bounding_box().offset(width.div_ceil(2).cast_signed());
+            let drawn = display.affected_area();
+            assert!(!drawn.is_zero_sized());
+            assert!(
+                bounds.contains(drawn.top_left) && bounds.contains(drawn.bottom_right().unwrap())
+            );
+
+            // The swirl starts at the center of the circle, which is between
+            // pixels for even diameters so only wider strokes cover it
+            if width > 1 {
+                assert_eq!(display.get_pixel(swirl.circle.center()), Some(BinaryColor::On));
+            }
+        }
+
+        // Nothing is drawn without a stroke
+        let mut display = MockDisplay::<BinaryColor>::new();
+        swirl.draw_styled(&PrimitiveStyle::with_fill(BinaryColor::On), &mut display).unwrap();
+        assert!(display.affected_area().is_zero_sized());
+    }
+}
+
+#[test]
+fn swirl_points_match_drawing() {
+    for swirl in SWIRLS {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        swirl.draw_styled(&PrimitiveStyle::with_stroke(BinaryColor::On, 1), &mut display).unwrap();
+
+        // Every point is drawn, in row-major order and only once
+        let mut points = MockDisplay::<BinaryColor>::new();
+        let mut previous = None;
+        for point in swirl.points() {
+            assert!(
+                previous
+                    .is_none_or(|previous: Point| (previous.y, previous.x) < (point.y, point.x))
+            );
+            previous = Some(point);
+            Pixel(point, BinaryColor::On).draw(&mut points).unwrap();
+        }
+        points.assert_eq(&display);
+    }
+}
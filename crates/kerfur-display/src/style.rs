@@ -6,7 +6,10 @@ use embedded_graphics::{
     primitives::PrimitiveStyle,
 };
 
+use crate::KerfurLayout;
+
 /// A style for drawing Kerfur
+#[derive(Clone, Copy)]
 pub struct KerfurStyle<C: PixelColor> {
     /// Style for the left eye inside
     pub left_eye_inner: PrimitiveStyle<C>,
@@ -14,6 +17,8 @@ pub struct KerfurStyle<C: PixelColor> {
     pub left_eye_outer: PrimitiveStyle<C>,
     /// Style for the left eye line
     pub left_eye_line: PrimitiveStyle<C>,
+    /// Style for the left eye highlights
+    pub left_eye_highlight: PrimitiveStyle<C>,
     /// Style for the left eyebrow
     pub left_eyebrow: PrimitiveStyle<C>,
 
@@ -23,6 +28,8 @@ pub struct KerfurStyle<C: PixelColor> {
     pub right_eye_outer: PrimitiveStyle<C>,
     /// Style for the right eye line
     pub right_eye_line: PrimitiveStyle<C>,
+    /// Style for the right eye highlights
+    pub right_eye_highlight: PrimitiveStyle<C>,
     /// Style for the right eyebrow
     pub right_eyebrow: PrimitiveStyle<C>,
 
@@ -37,7 +44,33 @@ pub struct KerfurStyle<C: PixelColor> {
     pub whisker: PrimitiveStyle<C>,
 }
 
-impl<C: PixelColor> KerfurStyle<C> {}
+impl<C: PixelColor> KerfurStyle<C> {
+    /// Scale the width of every stroke using the given layout.
+    #[must_use]
+    pub fn with_layout(&self, layout: &KerfurLayout) -> Self {
+        let stroke = |mut style: PrimitiveStyle<C>| {
+            style.stroke_width = layout.length(style.stroke_width);
+            style
+        };
+
+        Self {
+            left_eye_inner: stroke(self.left_eye_inner),
+            left_eye_outer: stroke(self.left_eye_outer),
+            left_eye_line: stroke(self.left_eye_line),
+            left_eye_highlight: stroke(self.left_eye_highlight),
+            left_eyebrow: stroke(self.left_eyebrow),
+            right_eye_inner: stroke(self.right_eye_inner),
+            right_eye_outer: stroke(self.right_eye_outer),
+            right_eye_line: stroke(self.right_eye_line),
+            right_eye_highlight: stroke(self.right_eye_highlight),
+            right_eyebrow: stroke(self.right_eyebrow),
+            nose: stroke(self.nose),
+            mouth: stroke(self.mouth),
+            mouth_bottom: stroke(self.mouth_bottom),
+            whisker: stroke(self.whisker),
+        }
+    }
+}
 
 // -------------------------------------------------------------------------------------------------
 
@@ -49,10 +82,12 @@ macro_rules! binary_style {
             left_eye_inner: binary_style!(@style fill),
             left_eye_outer: binary_style!(@style stroke),
             left_eye_line: binary_style!(@style stroke, 16),
+            left_eye_highlight: binary_style!(@style stroke),
             left_eyebrow: binary_style!(@style fill, stroke, 16),
             right_eye_inner: binary_style!(@style fill),
             right_eye_outer: binary_style!(@style stroke),
             right_eye_line: binary_style!(@style stroke, 16),
+            right_eye_highlight: binary_style!(@style stroke),
             right_eyebrow: binary_style!(@style fill, stroke, 16),
             nose: binary_style!(@style stroke),
             mouth: binary_style!(@style fill, stroke, 8),
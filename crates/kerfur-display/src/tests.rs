@@ -0,0 +1,168 @@
+use core::time::Duration;
+
+use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+use crate::{
+    Easing, Idle, KerfurDisplay, KerfurElements, KerfurEmote, KerfurExpression, KerfurLayout,
+    KerfurTransform, Keyframe, Repeat, Timeline, Transition,
+};
+
+/// A display that Kerfur can draw anywhere on.
+fn display() -> KerfurDisplay<'static, 'static, MockDisplay<BinaryColor>> {
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    display.set_allow_out_of_bounds_drawing(true);
+    KerfurDisplay::binary_on(display, KerfurEmote::Neutral)
+}
+
+const EXPRESSIONS: [KerfurEmote; 3] = [KerfurEmote::Meow, KerfurEmote::Sad, KerfurEmote::Happy];
+
+/// Keyframes that each show immediately and hold for 100ms.
+static HELD: [Keyframe; 3] = [
+    Keyframe::new(KerfurEmote::MEOW)
+        .with_transition(Transition::IMMEDIATE)
+        .with_hold(Duration::from_millis(100)),
+    Keyframe::new(KerfurEmote::SAD)
+        .with_transition(Transition::IMMEDIATE)
+        .with_hold(Duration::from_millis(100)),
+    Keyframe::new(KerfurEmote::HAPPY)
+        .with_transition(Transition::IMMEDIATE)
+        .with_hold(Duration::from_millis(100)),
+];
+
+/// Play a timeline, drawing a frame every `frame` milliseconds,
+/// and get the index of the expression shown on each frame.
+fn play<const N: usize>(timeline: Timeline<'_>, frame: u64) -> [usize; N] {
+    let mut kerfur = display();
+    kerfur.play(timeline);
+    core::array::from_fn(|index| {
+        let elapsed = if index == 0 { Duration::ZERO } else { Duration::from_millis(frame) };
+        kerfur.draw(elapsed).unwrap();
+        EXPRESSIONS.iter().position(|emote| emote.into_elements() == kerfur.current).unwrap()
+    })
+}
+
+#[test]
+fn timeline_once_stays_on_last() {
+    let shown = play(Timeline::new(&HELD), 50);
+    assert_eq!(shown, [0, 0, 1, 1, 2, 2, 2, 2]);
+
+    // Finishes once the last keyframe has been held
+    let mut kerfur = display();
+    kerfur.play(Timeline::new(&HELD));
+    for frame in 0..8 {
+        kerfur.draw(if frame == 0 { Duration::ZERO } else { Duration::from_millis(50) }).unwrap();
+        assert_eq!(kerfur.is_playing(), frame < 6, "Frame {frame}");
+    }
+}
+
+#[test]
+fn timeline_loop_wraps() {
+    let shown = play(Timeline::new(&HELD).with_repeat(Repeat::Loop), 50);
+    assert_eq!(shown, [0, 0, 1, 1, 2, 2, 0, 0]);
+}
+
+#[test]
+fn timeline_ping_pong_reverses() {
+    // Neither end is repeated when reversing
+    let shown = play(Timeline::new(&HELD).with_repeat(Repeat::PingPong), 100);
+    assert_eq!(shown, [0, 1, 2, 1, 0, 1, 2, 1]);
+}
+
+#[test]
+fn timeline_keeps_time() {
+    // Each keyframe takes 125ms, a 50ms transition then a 75ms hold
+    let transition = Transition::timed(Duration::from_millis(50), Easing::Linear);
+    let keyframes = HELD
+        .map(|keyframe| keyframe.with_transition(transition).with_hold(Duration::from_millis(75)));
+
+    // Frames never line up with keyframes, so time left over
+    // after each transition and hold must be carried forward
+    let mut kerfur = display();
+    kerfur.play(Timeline::new(&keyframes).with_repeat(Repeat::Loop));
+    for frame in 0..120 {
+        let elapsed = if frame == 0 { Duration::ZERO } else { Duration::from_millis(16) };
+        kerfur.draw(elapsed).unwrap();
+
+        let expected = (frame * 16 / 125) % keyframes.len();
+        assert!(kerfur.target == EXPRESSIONS[expected].into_elements(), "Frame {frame}");
+    }
+}
+
+#[test]
+fn timeline_keeps_time_with_springs() {
+    // Springs settle between frames, so the time left over after settling
+    // must be carried into the hold for keyframes to start on time
+    let transition = Transition::critical_spring(400.);
+    let keyframes = HELD
+        .map(|keyframe| keyframe.with_transition(transition).with_hold(Duration::from_millis(75)));
+    let starts = |frame: u64| -> [u64; 6] {
+        let mut kerfur = display();
+        kerfur.play(Timeline::new(&keyframes).with_repeat(Repeat::Loop));
+        kerfur.draw(Duration::ZERO).unwrap();
+        let (mut time, mut target) = (0, kerfur.target);
+        core::array::from_fn(|_| {
+            while kerfur.target == target {
+                kerfur.draw(Duration::from_millis(frame)).unwrap();
+                time += frame;
+            }
+            target = kerfur.target;
+            time
+        })
+    };
+
+    // Springs move in whole milliseconds, so millisecond frames are exact
+    let (exact, frames) = (starts(1), starts(16));
+    for (exact, start) in exact.into_iter().zip(frames) {
+        assert!((exact..exact + 16).contains(&start), "Started at {start}ms instead of {exact}ms");
+    }
+}
+
+#[test]
+fn transform_scales_lengths() {
+    // Mirrored faces keep their lengths, and flat faces keep them visible
+    for ((scale_x, scale_y), expected) in
+        [((2., 0.5), 10), ((-1., 1.), 10), ((1., -4.), 20), ((-1., -1.), 10), ((0., 1.), 1)]
+    {
+        let transform = KerfurTransform::IDENTITY.with_scale_xy(scale_x, scale_y);
+        assert_eq!(KerfurLayout::IDENTITY.with_transform(transform).length(10), expected);
+    }
+}
+
+/// Drive two idles with the same frames for 20 seconds, calling `check`
+/// with the elements each idle produces on every frame.
+fn idle_side_by_side(a: Idle, b: Idle, mut check: impl FnMut(KerfurElements, KerfurElements)) {
+    let (mut a, mut b) = (a.with_breathing(false), b.with_breathing(false));
+    for frame in 0..1200 {
+        // Uneven frame times, like a real display
+        let elapsed = Duration::from_millis(12 + frame % 9);
+        a.update(elapsed);
+        b.update(elapsed);
+
+        let (mut a_elements, mut b_elements) = (KerfurElements::new(), KerfurElements::new());
+        a.apply(&mut a_elements);
+        b.apply(&mut b_elements);
+        check(a_elements, b_elements);
+    }
+}
+
+#[test]
+fn idle_same_seed_repeats() {
+    // Check blinks and saccades separately, so both are known to happen
+    for (blinking, saccades) in [(true, false), (false, true)] {
+        let idle = Idle::new(1234).with_blinking(blinking).with_saccades(saccades);
+        let mut moved = false;
+        idle_side_by_side(idle, idle, |a, b| {
+            assert!(a == b);
+            moved |= a != KerfurElements::new();
+        });
+        assert!(moved);
+    }
+}
+
+#[test]
+fn idle_different_seeds_differ() {
+    let mut differed = false;
+    idle_side_by_side(Idle::new(1234), Idle::new(5678), |a, b| differed |= a != b);
+    assert!(differed);
+}
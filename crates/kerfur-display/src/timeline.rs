@@ -0,0 +1,201 @@
+//! Keyframe timelines, used to play back sequences of expressions.
+
+use core::time::Duration;
+
+use crate::{KerfurElements, KerfurExpression, Transition};
+
+/// A sequence of [`Keyframe`]s to play back.
+///
+/// ```rust
+/// use core::time::Duration;
+///
+/// use kerfur_display::{KerfurEmote, Keyframe, Repeat, Timeline};
+///
+/// // Blink every few seconds
+/// static BLINK: [Keyframe; 2] = [
+///     Keyframe::new(KerfurEmote::NEUTRAL).with_hold(Duration::from_secs(3)),
+///     Keyframe::new(KerfurEmote::BLINK).with_hold(Duration::from_millis(100)),
+/// ];
+/// static IDLE: Timeline<'static> = Timeline::new(&BLINK).with_repeat(Repeat::Loop);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct Timeline<'a> {
+    keyframes: &'a [Keyframe],
+    repeat: Repeat,
+}
+
+impl<'a> Timeline<'a> {
+    /// Create a new [`Timeline`] that plays the keyframes once.
+    #[inline]
+    #[must_use]
+    pub const fn new(keyframes: &'a [Keyframe]) -> Self { Self { keyframes, repeat: Repeat::Once } }
+
+    /// Set how the timeline repeats.
+    #[inline]
+    #[must_use]
+    pub const fn with_repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Get the keyframes in the timeline.
+    #[inline]
+    #[must_use]
+    pub const fn keyframes(&self) -> &'a [Keyframe] { self.keyframes }
+
+    /// Get how the timeline repeats.
+    #[inline]
+    #[must_use]
+    pub const fn repeat(&self) -> Repeat { self.repeat }
+}
+
+/// How a [`Timeline`] repeats after reaching the last keyframe.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Repeat {
+    /// Stop after the last keyframe
+    #[default]
+    Once,
+    /// Restart from the first keyframe
+    Loop,
+    /// Play the keyframes backwards, then forwards again
+    PingPong,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// An expression in a [`Timeline`].
+///
+/// Each keyframe animates toward its expression using its [`Transition`],
+/// then holds the expression for a duration before the next keyframe starts.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Keyframe {
+    elements: KerfurElements,
+    transition: Transition,
+    hold: Duration,
+}
+
+impl Keyframe {
+    /// Create a new [`Keyframe`] with the default transition and no hold.
+    #[inline]
+    #[must_use]
+    pub const fn new(elements: KerfurElements) -> Self {
+        Self { elements, transition: Transition::DEFAULT, hold: Duration::ZERO }
+    }
+
+    /// Create a new [`Keyframe`] from a [`KerfurExpression`].
+    #[inline]
+    #[must_use]
+    pub fn from_expression<E: KerfurExpression>(expression: E) -> Self {
+        Self::new(expression.into_elements())
+    }
+
+    /// Set the transition used to animate toward the keyframe.
+    #[inline]
+    #[must_use]
+    pub const fn with_transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// Set how long the keyframe is held after the transition finishes.
+    #[inline]
+    #[must_use]
+    pub const fn with_hold(mut self, hold: Duration) -> Self {
+        self.hold = hold;
+        self
+    }
+
+    /// Get the elements of the keyframe.
+    #[inline]
+    #[must_use]
+    pub const fn elements(&self) -> KerfurElements { self.elements }
+
+    /// Get the transition used to animate toward the keyframe.
+    #[inline]
+    #[must_use]
+    pub const fn transition(&self) -> Transition { self.transition }
+
+    /// Get how long the keyframe is held after the transition finishes.
+    #[inline]
+    #[must_use]
+    pub const fn hold(&self) -> Duration { self.hold }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The playback state of a [`Timeline`].
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct Playback<'a> {
+    timeline: Timeline<'a>,
+    index: usize,
+    reverse: bool,
+    held: Duration,
+}
+
+impl<'a> Playback<'a> {
+    /// Start playing a [`Timeline`].
+    ///
+    /// Returns `None` if the timeline has no keyframes.
+    pub(crate) fn new(timeline: Timeline<'a>) -> Option<Self> {
+        (!timeline.keyframes.is_empty()).then_some(Self {
+            timeline,
+            index: 0,
+            reverse: false,
+            held: Duration::ZERO,
+        })
+    }
+
+    /// Get the number of keyframes in the timeline.
+    pub(crate) const fn len(&self) -> usize { self.timeline.keyframes.len() }
+
+    /// Get the current keyframe.
+    pub(crate) fn keyframe(&self) -> Keyframe { self.timeline.keyframes[self.index] }
+
+    /// Advance the current keyframe's hold by the elapsed time.
+    pub(crate) fn hold(&mut self, elapsed: Duration) -> Step {
+        let hold = self.timeline.keyframes[self.index].hold;
+        let total = self.held.saturating_add(elapsed);
+        let Some(remaining) = total.checked_sub(hold) else {
+            self.held = total;
+            return Step::Hold;
+        };
+
+        self.held = Duration::ZERO;
+        match self.next_index() {
+            Some(index) => {
+                self.index = index;
+                Step::Next(remaining)
+            }
+            None => Step::Finished,
+        }
+    }
+
+    /// Get the index of the keyframe after the current one.
+    fn next_index(&mut self) -> Option<usize> {
+        let last = self.timeline.keyframes.len() - 1;
+        match self.timeline.repeat {
+            Repeat::Once => (self.index < last).then_some(self.index + 1),
+            Repeat::Loop => Some(if self.index < last { self.index + 1 } else { 0 }),
+            Repeat::PingPong => {
+                if last == 0 {
+                    return Some(0);
+                }
+                if (self.reverse && self.index == 0) || (!self.reverse && self.index == last) {
+                    self.reverse = !self.reverse;
+                }
+                Some(if self.reverse { self.index - 1 } else { self.index + 1 })
+            }
+        }
+    }
+}
+
+/// The result of advancing a [`Playback`].
+pub(crate) enum Step {
+    /// The current keyframe is still being held
+    Hold,
+    /// The next keyframe should start,
+    /// with the time left over after the hold finished
+    Next(Duration),
+    /// The timeline has finished
+    Finished,
+}
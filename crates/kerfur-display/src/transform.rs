@@ -0,0 +1,126 @@
+use embedded_graphics::prelude::*;
+
+use crate::math::{cosf, sinf, sqrtf};
+
+/// A transform applied to the whole face as it is drawn.
+///
+/// The face is scaled and rotated around the center of the
+/// [`KerfurLayout::FACE`](crate::KerfurLayout::FACE), then moved by the
+/// translation. The translation is in canvas pixels, so it is scaled along
+/// with the face to fit the display.
+///
+/// Points and lines follow the transform exactly, while ellipses are moved
+/// and scaled but never rotated. Circles and arcs stay round, and are scaled
+/// by the average of the two scales.
+///
+/// ```rust
+/// use embedded_graphics::prelude::Point;
+/// use kerfur_display::KerfurTransform;
+///
+/// // Tilt the head slightly clockwise and bob it upwards
+/// let tilt = KerfurTransform::IDENTITY.with_rotation(0.1).with_translation(Point::new(0, -8));
+///
+/// // Squash the face, keeping the chin in place
+/// let squash =
+///     KerfurTransform::IDENTITY.with_scale_xy(1.1, 0.9).with_translation(Point::new(0, 14));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KerfurTransform {
+    translation: Point,
+    scale_x: f32,
+    scale_y: f32,
+    rotation: f32,
+}
+
+impl KerfurTransform {
+    /// A transform that leaves the face as-is.
+    pub const IDENTITY: Self =
+        Self { translation: Point::zero(), scale_x: 1., scale_y: 1., rotation: 0. };
+
+    /// Move the face by the given offset, in canvas pixels.
+    #[inline]
+    #[must_use]
+    pub const fn with_translation(mut self, translation: Point) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    /// Scale the face uniformly by the given factor.
+    #[inline]
+    #[must_use]
+    pub const fn with_scale(self, scale: f32) -> Self { self.with_scale_xy(scale, scale) }
+
+    /// Scale the face horizontally and vertically by separate factors.
+    ///
+    /// Both factors should be positive, negative factors would mirror the face.
+    #[inline]
+    #[must_use]
+    pub const fn with_scale_xy(mut self, scale_x: f32, scale_y: f32) -> Self {
+        self.scale_x = scale_x;
+        self.scale_y = scale_y;
+        self
+    }
+
+    /// Rotate the face by the given angle, in radians.
+    ///
+    /// Positive angles rotate the face clockwise. Only small angles look
+    /// right, as ellipses and rectangles are not rotated with the face.
+    #[inline]
+    #[must_use]
+    pub const fn with_rotation(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Get the offset the face is moved by, in canvas pixels.
+    #[inline]
+    #[must_use]
+    pub const fn translation(&self) -> Point { self.translation }
+
+    /// Get the horizontal scale of the face.
+    #[inline]
+    #[must_use]
+    pub const fn scale_x(&self) -> f32 { self.scale_x }
+
+    /// Get the vertical scale of the face.
+    #[inline]
+    #[must_use]
+    pub const fn scale_y(&self) -> f32 { self.scale_y }
+
+    /// Get the angle the face is rotated by, in radians.
+    #[inline]
+    #[must_use]
+    pub const fn rotation(&self) -> f32 { self.rotation }
+
+    /// Returns `true` if the face is scaled equally in both directions.
+    #[inline]
+    #[must_use]
+    pub fn is_uniform(&self) -> bool { (self.scale_x - self.scale_y).abs() <= f32::EPSILON }
+
+    /// Get the scale applied to lengths, the geometric mean of the two scales.
+    ///
+    /// Mirroring doesn't change lengths, so only the size of each scale is
+    /// used.
+    #[must_use]
+    pub(crate) fn length_scale(&self) -> f32 { sqrtf((self.scale_x * self.scale_y).abs()) }
+
+    /// Scale and rotate a vector, without moving it.
+    #[must_use]
+    pub(crate) fn vector(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        let (x, y) = (x * self.scale_x, y * self.scale_y);
+        let (sin, cos) = (sinf(self.rotation), cosf(self.rotation));
+        (x * cos - y * sin, x * sin + y * cos)
+    }
+
+    /// Undo [`KerfurTransform::vector`].
+    #[must_use]
+    pub(crate) fn inverse_vector(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        let (sin, cos) = (sinf(self.rotation), cosf(self.rotation));
+        let (x, y) = (x * cos + y * sin, y * cos - x * sin);
+        (x / self.scale_x, y / self.scale_y)
+    }
+}
+
+impl Default for KerfurTransform {
+    fn default() -> Self { Self::IDENTITY }
+}
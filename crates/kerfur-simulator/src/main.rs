@@ -1,39 +1,86 @@
 //! TODO
 #![expect(clippy::cast_precision_loss, reason = "Framerate should never be that high")]
 
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
 
 use embedded_graphics_core::{pixelcolor::Rgb565, prelude::*};
 use embedded_graphics_simulator::{
     OutputSettings, SimulatorDisplay, SimulatorEvent, Window,
     sdl2::{Keycode, MouseButton},
 };
-use kerfur_display::{KerfurDisplay, KerfurEmote};
+use kerfur_display::{Idle, KerfurDisplay, KerfurEmote, KerfurTransform, Transition};
 
 /// The target framerate of the simulator window
 const FRAMERATE: u32 = 165;
 const FRAMETIME: f32 = 1.0 / FRAMERATE as f32;
 
+/// How quickly the mouth opens and closes while talking
+const TALKING_SPEED: f32 = 8.0;
+
+/// How quickly the head bobs up and down while bobbing
+const BOBBING_SPEED: f32 = 6.0;
+
+/// The default size of the simulated display
+const DISPLAY_SIZE: Size = Size::new_equal(480);
+
 fn main() {
     let mut window = Window::new("Kerfur Simulator", &OutputSettings::default());
 
-    let display = SimulatorDisplay::<Rgb565>::new(Size::new(480, 480));
-    let mut kerfur = KerfurDisplay::blue_565(display, KerfurEmote::Neutral);
+    // Use the display size passed as an argument, such as `240x240`
+    let size = std::env::args().nth(1).map_or(DISPLAY_SIZE, |arg| {
+        let (width, height) = arg.split_once('x').expect("Display size should be `WIDTHxHEIGHT`");
+        Size::new(
+            width.parse().expect("Display width should be a number"),
+            height.parse().expect("Display height should be a number"),
+        )
+    });
+
+    // Use the seed passed as an argument for repeatable idle movements
+    let seed = std::env::args().nth(2).map_or_else(
+        || SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().subsec_nanos(),
+        |arg| arg.parse().expect("Idle seed should be a number"),
+    );
 
-    let mut neutral = true;
-    let mut blink_counter = 0u32;
+    let display = SimulatorDisplay::<Rgb565>::new(size);
+    let mut kerfur =
+        KerfurDisplay::blue_565(display, KerfurEmote::Neutral).with_idle(Idle::new(seed));
 
     let mut locked = false;
+    let mut follow = false;
+    let mut talking: Option<Instant> = None;
+    let mut bobbing: Option<Instant> = None;
     let mut instant = Instant::now();
+    let mut frame = Instant::now();
 
     loop {
+        // Open and close the mouth while talking
+        if let Some(start) = talking {
+            let elapsed = start.elapsed().as_secs_f32();
+            kerfur.set_mouth_openness((elapsed * TALKING_SPEED).sin().abs());
+        }
+
+        // Bob the head up and down, tilting it from side to side
+        if let Some(start) = bobbing {
+            let elapsed = start.elapsed().as_secs_f32() * BOBBING_SPEED;
+            let bounce = elapsed.sin().abs();
+            #[expect(clippy::cast_possible_truncation, reason = "The bounce is always small")]
+            let height = (bounce * 12.0).round() as i32;
+            kerfur.set_transform(
+                KerfurTransform::IDENTITY
+                    .with_translation(Point::new(0, -height))
+                    .with_scale_xy(1.0 + (1.0 - bounce) * 0.04, 1.0 - (1.0 - bounce) * 0.04)
+                    .with_rotation((elapsed / 2.0).sin() * 0.08),
+            );
+        }
+
         // Draw the kerfur display
         kerfur.clear(Rgb565::BLACK).unwrap();
-        kerfur.draw(5.).unwrap();
+        kerfur.draw(frame.elapsed()).unwrap();
+        frame = Instant::now();
 
         // Simulate spaces between pixels
         kerfur
-            .draw_iter(ScanlineIterator::<_, 2>::new(Point::new_equal(480), Rgb565::BLACK))
+            .draw_iter(ScanlineIterator::<_, 2>::new(Point::zero() + size, Rgb565::BLACK))
             .unwrap();
 
         // Update the window and handle events
@@ -45,63 +92,117 @@ fn main() {
                 // Toggle lock on SPACE key, preventing expression changes
                 SimulatorEvent::KeyDown { keycode: Keycode::SPACE, .. } => {
                     if locked {
-                        neutral = true;
                         locked = false;
                         kerfur.set_expression(KerfurEmote::Neutral);
                     } else {
                         locked = true;
                     }
                 }
+                // Toggle following the mouse on G key
+                SimulatorEvent::KeyDown { keycode: Keycode::G, .. } => {
+                    follow = !follow;
+                    if !follow {
+                        kerfur.look_forward();
+                    }
+                }
+                SimulatorEvent::MouseMove { point } if follow => kerfur.look_at_converging(point),
+                // Twitch the whiskers on N key
+                SimulatorEvent::KeyDown { keycode: Keycode::N, .. } => kerfur.twitch_whiskers(),
+                // Toggle bobbing on B key
+                SimulatorEvent::KeyDown { keycode: Keycode::B, .. } => {
+                    bobbing = if bobbing.is_some() {
+                        kerfur.set_transform(KerfurTransform::IDENTITY);
+                        None
+                    } else {
+                        Some(Instant::now())
+                    };
+                }
+                // Toggle talking on T key
+                SimulatorEvent::KeyDown { keycode: Keycode::T, .. } => {
+                    talking = if talking.is_some() {
+                        kerfur.set_mouth_openness(0.);
+                        None
+                    } else {
+                        Some(Instant::now())
+                    };
+                }
                 // Don't change expressions if locked
                 _ if locked => {}
                 // Display various expressions based on input
                 SimulatorEvent::MouseButtonDown { mouse_btn: MouseButton::Left, .. } => {
-                    kerfur.set_expression(KerfurEmote::Meow);
-                    neutral = false;
+                    kerfur.set_expression_with(KerfurEmote::Meow, Transition::BOUNCY);
                 }
                 SimulatorEvent::MouseButtonDown { mouse_btn: MouseButton::Right, .. } => {
                     kerfur.set_expression(KerfurEmote::Dazed);
-                    neutral = false;
+                }
+                SimulatorEvent::MouseButtonDown { mouse_btn: MouseButton::Middle, .. } => {
+                    kerfur.set_expression(KerfurEmote::Dizzy);
                 }
                 SimulatorEvent::KeyDown { keycode: Keycode::UP, .. } => {
                     kerfur.set_expression(KerfurEmote::NeutralUp);
-                    neutral = false;
                 }
                 SimulatorEvent::KeyDown { keycode: Keycode::DOWN, .. } => {
                     kerfur.set_expression(KerfurEmote::NeutralDown);
-                    neutral = false;
                 }
                 SimulatorEvent::KeyDown { keycode: Keycode::LEFT, .. } => {
                     kerfur.set_expression(KerfurEmote::NeutralLeft);
-                    neutral = false;
                 }
                 SimulatorEvent::KeyDown { keycode: Keycode::RIGHT, .. } => {
                     kerfur.set_expression(KerfurEmote::NeutralRight);
-                    neutral = false;
+                }
+                SimulatorEvent::KeyDown { keycode: Keycode::NUM_1, .. } => {
+                    kerfur.set_expression(KerfurEmote::WinkLeft);
+                }
+                SimulatorEvent::KeyDown { keycode: Keycode::NUM_2, .. } => {
+                    kerfur.set_expression(KerfurEmote::WinkRight);
+                }
+                SimulatorEvent::KeyDown { keycode: Keycode::NUM_3, .. } => {
+                    kerfur.set_expression(KerfurEmote::Skeptical);
+                }
+                SimulatorEvent::KeyDown { keycode: Keycode::NUM_4, .. } => {
+                    kerfur.set_expression(KerfurEmote::Smirk);
+                }
+                SimulatorEvent::KeyDown { keycode: Keycode::NUM_5, .. } => {
+                    kerfur.set_expression(KerfurEmote::Happy);
+                }
+                SimulatorEvent::KeyDown { keycode: Keycode::NUM_6, .. } => {
+                    kerfur.set_expression(KerfurEmote::Sad);
+                }
+                SimulatorEvent::KeyDown { keycode: Keycode::NUM_7, .. } => {
+                    kerfur.set_expression(KerfurEmote::Angry);
+                }
+                SimulatorEvent::KeyDown { keycode: Keycode::NUM_8, .. } => {
+                    kerfur.set_expression(KerfurEmote::Sleepy);
+                }
+                SimulatorEvent::KeyDown { keycode: Keycode::NUM_9, .. } => {
+                    kerfur.set_expression_with(KerfurEmote::Surprised, Transition::BOUNCY);
+                }
+                SimulatorEvent::KeyDown { keycode: Keycode::NUM_0, .. } => {
+                    kerfur.set_expression(KerfurEmote::Love);
                 }
                 SimulatorEvent::MouseButtonUp { .. }
                 | SimulatorEvent::KeyUp {
-                    keycode: Keycode::UP | Keycode::DOWN | Keycode::LEFT | Keycode::RIGHT,
+                    keycode:
+                        Keycode::UP
+                        | Keycode::DOWN
+                        | Keycode::LEFT
+                        | Keycode::RIGHT
+                        | Keycode::NUM_1
+                        | Keycode::NUM_2
+                        | Keycode::NUM_3
+                        | Keycode::NUM_4
+                        | Keycode::NUM_5
+                        | Keycode::NUM_6
+                        | Keycode::NUM_7
+                        | Keycode::NUM_8
+                        | Keycode::NUM_9
+                        | Keycode::NUM_0,
                     ..
-                } => {
-                    kerfur.set_expression(KerfurEmote::Neutral);
-                    neutral = true;
-                }
+                } => kerfur.set_expression(KerfurEmote::Neutral),
                 _ => {}
             }
         }
 
-        // Handle blinking when using the neutral expression
-        if neutral {
-            blink_counter += 1;
-            if blink_counter >= 230 {
-                blink_counter = 0;
-                kerfur.set_expression(KerfurEmote::Neutral);
-            } else if blink_counter == 200 {
-                kerfur.set_expression(KerfurEmote::Blink);
-            }
-        }
-
         // Get elapsed time and reset the instant
         let elapsed = instant.elapsed();
         instant = Instant::now();
@@ -6,21 +6,22 @@ use ef_st7701s::{
     color::Rgb565,
     interface::{Generic16BitBus, ParallelInterface},
 };
-use embassy_time::{Delay, Duration, Timer};
+use embassy_time::{Delay, Duration, Instant, Timer};
 use esp_hal::{
     gpio::{AnyPin, Level, NoPin, Output, OutputConfig},
     mcpwm::{McPwm, PeripheralClockConfig, operator::PwmPinConfig, timer::PwmWorkingMode},
     peripherals::MCPWM0,
     time::Rate,
 };
-use kerfur_display::{KerfurDisplay, KerfurEmote};
+use kerfur_display::{Idle, KerfurDisplay, KerfurEmote};
 
 use crate::{
     app::SPI,
-    signal::{DISPLAY_CMD, DisplayCommand},
+    signal::{DISPLAY_CMD, DISPLAY_GAZE, DisplayCommand},
 };
 
-const TICKRATE: f32 = 5.0;
+/// The time between frames while animating.
+const FRAMETIME: Duration = Duration::from_hz(60);
 
 /// A task that handles drawing to the display.
 #[embassy_executor::task]
@@ -57,9 +58,11 @@ pub(super) async fn task(spi: &'static SPI, mut p: DisplayPeripherals<'static>)
     );
 
     // Wrap the display in a KerfurDisplay
-    let mut kerfur: KerfurDisplay<'static, _> =
-        KerfurDisplay::blue_565(display.release(), KerfurEmote::Neutral);
-    if kerfur.draw(TICKRATE).is_err() {
+    #[expect(clippy::cast_possible_truncation, reason = "Only used as a random seed")]
+    let idle = Idle::new(Instant::now().as_ticks() as u32);
+    let mut kerfur: KerfurDisplay<'static, 'static, _> =
+        KerfurDisplay::blue_565(display.release(), KerfurEmote::Neutral).with_idle(idle);
+    if kerfur.draw(core::time::Duration::ZERO).is_err() {
         defmt::warn!("Failed to draw initial frame, ignoring...");
     }
 
@@ -67,25 +70,60 @@ pub(super) async fn task(spi: &'static SPI, mut p: DisplayPeripherals<'static>)
     let _backlight = Output::new(p.display_backlight, Level::High, OutputConfig::default());
 
     defmt::info!("Display initialized!");
+    let (mut delay, mut hold) = (Duration::MIN, None);
     loop {
-        // Wait for a display command
-        let (emote, delay) = match DISPLAY_CMD.receive().await {
-            DisplayCommand::Push(emote) => (emote, Duration::MIN),
-            DisplayCommand::PushHold(emote, delay) => (emote, delay),
-        };
-
         // Draw and animate the emote
-        kerfur.set_expression(emote);
+        let mut frame = Instant::now();
         while kerfur.is_animating() {
+            let elapsed = core::time::Duration::from_micros(frame.elapsed().as_micros());
+            frame = Instant::now();
+
+            // Look at the latest gaze target
+            match DISPLAY_GAZE.try_take() {
+                Some(Some(target)) => kerfur.look_at_converging(target),
+                Some(None) => kerfur.look_forward(),
+                None => {}
+            }
+
             // Draw to the display
-            if kerfur.draw(TICKRATE).is_err() {
+            if kerfur.draw(elapsed).is_err() {
                 defmt::error!("Failed to draw to display, waiting before retrying...");
                 Timer::after_secs(5).await;
                 defmt::warn!("Resuming display task...");
             }
+
+            // Start holding the emote once it has finished changing
+            if hold.is_none() && kerfur.progress() >= 1. {
+                hold = Some(Instant::now() + delay);
+            }
+
+            // Stop animating if another command is waiting and the emote has been held,
+            // some expressions (like spinning eyes or idle movements) never finish
+            if !DISPLAY_CMD.is_empty() && hold.is_some_and(|until| Instant::now() >= until) {
+                break;
+            }
+
+            // Let other tasks run before drawing the next frame
+            Timer::after(FRAMETIME).await;
         }
+        Timer::at(hold.unwrap_or_else(|| Instant::now() + delay)).await;
 
-        Timer::after(delay).await;
+        // Wait for a display command
+        delay = match DISPLAY_CMD.receive().await {
+            DisplayCommand::Push(emote) => {
+                kerfur.set_expression(emote);
+                Duration::MIN
+            }
+            DisplayCommand::PushHold(emote, delay) => {
+                kerfur.set_expression(emote);
+                delay
+            }
+            DisplayCommand::Play(timeline) => {
+                kerfur.play(timeline);
+                Duration::MIN
+            }
+        };
+        hold = None;
     }
 }
 
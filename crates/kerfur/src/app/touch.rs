@@ -3,8 +3,9 @@
 use ef_gt911::{GT911, GT911Error};
 use embassy_embedded_hal::shared_bus::asynch::i2c::I2cDevice;
 use embassy_time::Timer;
+use embedded_graphics::prelude::Point;
 
-use crate::app::I2C;
+use crate::{app::I2C, signal::DISPLAY_GAZE};
 
 /// Address of the GT911 touch sensor.
 const GT911_ADDRESS: u8 = 0x5D;
@@ -30,10 +31,20 @@ pub(super) async fn task(i2c: &'static I2C) -> ! {
     }
     defmt::info!("Touch sensor configured!");
 
+    let mut touching = false;
     loop {
         // Query for all touch points
         match touch.query_touch_all_async().await {
             Ok(points) => {
+                // Look at the first touch point, or forward when released
+                let first = points.iter().flatten().next();
+                if let Some(point) = first {
+                    DISPLAY_GAZE.signal(Some(Point::new(i32::from(point.x), i32::from(point.y))));
+                } else if touching {
+                    DISPLAY_GAZE.signal(None);
+                }
+                touching = first.is_some();
+
                 for point in points {
                     if let Some(point) = point {
                         defmt::info!(
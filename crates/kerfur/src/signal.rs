@@ -7,8 +7,9 @@ use embassy_sync::{
     blocking_mutex::raw::CriticalSectionRawMutex, channel::Channel, signal::Signal,
 };
 use embassy_time::Duration;
+use embedded_graphics::prelude::Point;
 use esp_hal::i2s::master::Config as I2sConfig;
-use kerfur_display::{KerfurElements, KerfurExpression};
+use kerfur_display::{KerfurElements, KerfurExpression, Timeline};
 
 /// A [`Signal`] that enables the audio subsystem.
 ///
@@ -22,13 +23,21 @@ pub(crate) static AUDIO_CFG: Signal<CriticalSectionRawMutex, I2sConfig> = Signal
 /// Commands sent through this channel will be executed by the display task.
 pub(crate) static DISPLAY_CMD: Channel<CriticalSectionRawMutex, DisplayCommand, 8> = Channel::new();
 
+/// A [`Signal`] for the point on the display Kerfur should look at.
+///
+/// `None` makes Kerfur look forward again.
+pub(crate) static DISPLAY_GAZE: Signal<CriticalSectionRawMutex, Option<Point>> = Signal::new();
+
 /// A command sent to the display task.
 #[derive(Clone, Copy, PartialEq)]
 pub(crate) enum DisplayCommand {
     /// Push an emote to the display.
     Push(KerfurElements),
-    /// Push an emote to the display for a duration.
+    /// Push an emote to the display, holding it for a duration once it has
+    /// finished changing.
     PushHold(KerfurElements, Duration),
+    /// Play a timeline of emotes on the display.
+    Play(Timeline<'static>),
 }
 
 impl DisplayCommand {